@@ -6,6 +6,8 @@ about-ruffle-build-time = Build Time
 about-ruffle-build-features = Build Features
 about-ruffle-commit-ref = Commit Ref
 about-ruffle-commit-time = Commit Time
+about-ruffle-copy-version-info = Copy
+about-ruffle-copied = Copied!
 
 about-ruffle-visit-website = Website
 about-ruffle-visit-github = Github
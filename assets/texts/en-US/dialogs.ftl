@@ -1,2 +1,110 @@
 dialog-ok = OK
-dialog-cancel = Cancel
\ No newline at end of file
+dialog-cancel = Cancel
+
+error-title = Error
+error-copy = Copy
+error-load-failed = Couldn't load { $source }
+
+browser-failed-title = Couldn't Open Browser
+browser-failed-message = Couldn't open a web browser. You can copy the address and open it yourself:
+browser-failed-copy = Copy
+
+warning-report = Report
+warning-silence-movie = Don't show again for this movie
+warning-dismiss = Dismiss
+warning-dismiss-all = Dismiss All
+warning-show-more = { $count ->
+    [one] Show 1 more warning
+   *[other] Show { $count } more warnings
+}
+
+bookmark-add = Add Bookmark
+bookmark-name-hint = Name
+bookmark-invalid = Invalid bookmark { $url }: { $error }
+bookmarks-manage = Manage Bookmarks
+bookmarks-empty = There are no bookmarks yet
+bookmarks-move-up = Move Up
+bookmarks-move-down = Move Down
+bookmarks-delete = Delete
+
+playlist = Playlist
+playlist-add-files = Add Files...
+playlist-add-current = Add Current Movie
+playlist-clear = Clear
+playlist-auto-advance = Auto-advance
+playlist-loop = Loop Playlist
+playlist-empty = The playlist is empty
+playlist-move-up = Move Up
+playlist-move-down = Move Down
+playlist-remove = Remove
+
+go-to-frame = Go to Frame
+go-to-frame-hint = 1 to { $count }
+go-to-frame-invalid = Enter a frame number from 1 to { $count }
+
+exit-confirm-title = Exit Ruffle?
+exit-confirm-message = A movie is still loaded. Do you want to exit anyway?
+exit-confirm-exit = Exit
+
+console-level-error = Errors
+console-level-warn = Warnings
+console-level-info = Info
+console-level-debug = Debug
+console-search = Search
+console-wrap = Wrap Lines
+console-clear = Clear
+console-copy-visible = Copy Visible
+console-save = Save...
+console-saved = Console output saved to { $path }
+console-save-failed = Couldn't save console output: { $error }
+
+drop-to-open = Drop to open
+drop-unsupported = This file type can't be opened
+drop-unsupported-ignored = Didn't open { $name }, as it isn't a project file
+
+click-to-play = Click to play
+
+screenshot-saved = Screenshot saved to { $path }
+screenshot-failed = Couldn't save screenshot: { $error }
+frame-copied = Frame copied to the clipboard
+frame-copy-failed = Couldn't copy frame: { $error }
+watched-file-reloaded = File changed, reloading
+stage-coordinates-copied = Copied { $coordinates }
+location-copied = Location copied to the clipboard
+gamepad-connected = Gamepad connected: { $name }
+gamepad-disconnected = Gamepad disconnected: { $name }
+
+update-check-title = Check for Updates
+update-check-checking = Checking for updates...
+update-check-available = A newer version of Ruffle is available: { $version }
+update-check-up-to-date = You're using the latest version of Ruffle.
+update-check-failed = Couldn't check for updates. Please check your internet connection, or try again later.
+update-check-download = Download
+
+record-frames = Record Frames
+record-frames-count = Frames
+record-frames-folder = Folder
+record-frames-no-folder = No folder chosen
+record-frames-browse = Browse...
+record-frames-start = Record
+record-frames-progress = { $saved } / { $count } frames
+record-frames-done = Saved { $count } frames to { $path }
+record-frames-failed = Couldn't record frames: { $error }
+
+movie-info = Movie Information
+movie-info-no-movie = No movie loaded
+movie-info-dimensions = Dimensions
+movie-info-frame-rate = Frame Rate
+movie-info-frame-count = Frame Count
+movie-info-swf-version = SWF Version
+movie-info-compression = Compression
+movie-info-compression-none = None
+movie-info-background-color = Background Color
+movie-info-file-size = File Size
+movie-info-file-size-value = { $kilobytes } KB ({ $bytes } bytes)
+
+status-bar-no-movie = No movie loaded
+status-bar-playing = Playing
+status-bar-paused = Paused
+status-bar-frame = Frame { $current } / { $total }
+status-bar-frame-rate = { $rate } fps
@@ -7,15 +7,113 @@
 # Some items may be also be duplicated here, like "About Ruffle" in the menu and "About Ruffle" elsewhere as the header of the about menu.
 # This is because some languages may use different wording for one or the other, especially with things like capitalisation.
 
+# The name of this language, written in the language itself, as it's listed in the language menu.
+language-name = English (US)
+
 file-menu = File
 file-menu-open-file = Open File...
+file-menu-open-file-new-window = Open in New Window...
 file-menu-open-url = Open URL...
+file-menu-open-recent = Open Recent
+file-menu-clear-recent = Clear Recent
+file-menu-playlist = Playlist
+file-menu-reload = Reload
+file-menu-copy-location = Copy Location
+file-menu-take-screenshot = Take Screenshot...
 file-menu-close = Close
 file-menu-exit = Exit
 
+edit-menu = Edit
+edit-menu-cut = Cut
+edit-menu-copy = Copy
+edit-menu-paste = Paste
+edit-menu-select-all = Select All
+edit-menu-copy-image = Copy Image
+
+view-menu = View
+view-menu-fullscreen = Fullscreen
+view-menu-hide-menu-bar = Hide Menu Bar
+view-menu-always-on-top = Always on Top
+view-menu-quality = Quality
+view-menu-quality-low = Low
+view-menu-quality-medium = Medium
+view-menu-quality-high = High
+view-menu-quality-best = Best
+view-menu-scale-mode = Scale Mode
+view-menu-scale-mode-exact-fit = Stretch to Fit
+view-menu-scale-mode-no-border = Crop to Fill
+view-menu-scale-mode-show-all = Fit Inside
+view-menu-scale-mode-no-scale = Unscaled (100%)
+view-menu-scale-mode-force = Override Movie's Scale Mode
+view-menu-letterbox = Letterbox
+view-menu-letterbox-on = On
+view-menu-letterbox-off = Off
+view-menu-letterbox-fullscreen = Fullscreen Only
+view-menu-letterbox-color = Color
+view-menu-zoom = Zoom ({ $percent }%)
+view-menu-zoom-in = Zoom In
+view-menu-zoom-out = Zoom Out
+view-menu-zoom-reset = Actual Size
+view-menu-zoom-custom = Custom
+view-menu-match-window-size = Match Window to Movie
+view-menu-movie-info = Movie Information
+view-menu-show-status-bar = Show Status Bar
+
+controls-menu = Controls
+controls-menu-resume = Resume
+controls-menu-pause = Pause
+controls-menu-step-frame = Step Frame
+controls-menu-rewind = Rewind
+controls-menu-previous = Previous
+controls-menu-next = Next
+controls-menu-volume = Volume
+controls-menu-mute = Mute
+
+bookmarks-menu = Bookmarks
+bookmarks-menu-add = Add Bookmark...
+bookmarks-menu-manage = Manage Bookmarks...
+
+settings-menu = Settings
+settings-menu-language = Language
+settings-menu-language-automatic = Automatic (System)
+settings-menu-theme = Theme
+settings-menu-theme-system = System
+settings-menu-theme-light = Light
+settings-menu-theme-dark = Dark
+settings-menu-theme-high-contrast = High Contrast
+settings-menu-auto-hide-menu = Hide Menu in Fullscreen
+settings-menu-pause-on-unfocus = Pause When Unfocused
+settings-menu-confirm-exit = Confirm Before Exiting
+settings-menu-kiosk = Kiosk Mode
+settings-menu-reset-movie-settings = Reset This Movie's Settings
+settings-menu-reset-movie-settings-tooltip = Play this movie with the default quality, scale mode, volume and zoom again
+settings-menu-preferences = Preferences...
+
+debug-menu = Debug
+debug-menu-show-fps = Show FPS
+debug-menu-show-console = Show Console
+debug-menu-show-stage-coordinates = Show Stage Coordinates
+debug-menu-show-stage-coordinates-tooltip = Show the stage coordinates under the pointer, and copy them by clicking
+debug-menu-watch-file = Watch File
+debug-menu-watch-file-tooltip = Reload the movie whenever its file changes
+debug-menu-go-to-frame = Go to Frame...
+debug-menu-previous-frame = Previous Frame
+debug-menu-next-frame = Next Frame
+debug-menu-record-frames = Record Frames...
+
+context-menu-copy-image = Copy Image
+context-menu-about = About Ruffle
+
 help-menu = Help
 help-menu-join-discord = Join Discord
 help-menu-report-a-bug = Report a Bug...
 help-menu-sponsor-development = Sponsor Development...
 help-menu-translate-ruffle = Translate Ruffle...
-help-menu-about = About Ruffle
\ No newline at end of file
+help-menu-check-for-updates = Check for Updates...
+help-menu-open-data-folder = Open Data Folder
+help-menu-open-log-file = Open Log File
+help-menu-about = About Ruffle
+
+# Tooltips of the speaker at the end of the menu bar, which toggles whether audio is muted.
+menu-bar-mute = Mute
+menu-bar-unmute = Unmute
\ No newline at end of file
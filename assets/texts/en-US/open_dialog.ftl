@@ -1 +1,15 @@
-open-url = Open URL
\ No newline at end of file
+open-url = Open URL
+open-url-hint = A URL or a path to a local file
+open-url-invalid = Not a valid URL or an existing file: { $error }
+open-url-missing-file = This looks like a path to a file, but there's no file at { $path }
+open-url-missing-scheme = This looks like a web address without http:// or https:// in front. Did you mean { $url }?
+open-url-unsupported-scheme = Movies can't be opened over { $scheme }. Use an http:// or https:// address, or a local file.
+open-url-fix = Open { $url }
+open-url-history = Recent
+open-url-clear-history = Clear History
+open-url-parameters = Parameters
+open-url-parameter-name = Name
+open-url-parameter-value = Value
+open-url-parameter-add = Add Parameter
+open-url-parameter-remove = Remove
+open-url-parameter-missing-name = Every parameter needs a name
@@ -0,0 +1,71 @@
+preferences = Preferences
+preferences-not-saved = Another Ruffle window was opened first, so changes made here won't be saved.
+
+preferences-general = General
+preferences-language = Language
+preferences-check-for-updates = Check for Updates on Startup
+
+preferences-playback = Playback
+preferences-quality = Quality
+preferences-volume = Volume
+preferences-mute = Mute
+preferences-autoplay = Autoplay
+preferences-autoplay-on = On
+preferences-autoplay-muted = Muted
+preferences-autoplay-off = Off (Click to Play)
+preferences-max-fps = Max FPS
+preferences-max-fps-off = Off
+preferences-max-fps-30 = 30
+preferences-max-fps-60 = 60
+preferences-max-fps-match-display = Match Display
+preferences-max-fps-current = Currently { $fps } FPS
+preferences-forward-mouse-wheel = Forward Mouse Wheel to Movie
+preferences-forward-mouse-wheel-tooltip = When on, scrolling moves a zoomed-in movie around. When off, or when there is nothing to scroll, scrolling zooms the view instead. Scrolling while holding Ctrl (Cmd on macOS) always zooms
+
+preferences-interface = Interface
+preferences-theme = Theme
+preferences-ui-scale = UI Scale
+preferences-reduce-motion = Reduce Motion
+preferences-reduce-motion-tooltip = Show menus, dialogs and messages without animating them. Movies aren't affected.
+preferences-reduce-motion-system = Follow System
+preferences-reduce-motion-on = On
+preferences-reduce-motion-off = Off
+preferences-start-fullscreen = Start in Fullscreen
+
+preferences-shortcuts = Keyboard Shortcuts
+preferences-shortcut-open-file = Open File
+preferences-shortcut-reload = Reload
+preferences-shortcut-take-screenshot = Take Screenshot
+preferences-shortcut-copy-frame = Copy Image
+preferences-shortcut-quit = Exit
+preferences-shortcut-play-pause = Play / Pause
+preferences-shortcut-fullscreen = Fullscreen
+preferences-shortcut-zoom-in = Zoom In
+preferences-shortcut-zoom-out = Zoom Out
+preferences-shortcut-zoom-reset = Actual Size
+preferences-shortcut-increase-ui-scale = Increase UI Scale
+preferences-shortcut-decrease-ui-scale = Decrease UI Scale
+preferences-shortcut-kiosk = Kiosk Mode
+preferences-shortcut-hide-menu-bar = Hide Menu Bar
+preferences-shortcut-cycle-language = Switching Language
+preferences-shortcut-press-key = Press a key...
+preferences-shortcut-conflict = { $shortcut } is already used for { $action }
+
+preferences-gamepad = Gamepad
+preferences-gamepad-none = None
+preferences-gamepad-south = A / Cross
+preferences-gamepad-east = B / Circle
+preferences-gamepad-north = Y / Triangle
+preferences-gamepad-west = X / Square
+preferences-gamepad-left-trigger = Left Bumper
+preferences-gamepad-left-trigger-2 = Left Trigger
+preferences-gamepad-right-trigger = Right Bumper
+preferences-gamepad-right-trigger-2 = Right Trigger
+preferences-gamepad-select = Select / Back
+preferences-gamepad-start = Start
+preferences-gamepad-dpad-up = D-Pad Up
+preferences-gamepad-dpad-down = D-Pad Down
+preferences-gamepad-dpad-left = D-Pad Left
+preferences-gamepad-dpad-right = D-Pad Right
+
+preferences-reset = Reset to Defaults
@@ -1,43 +1,69 @@
 use crate::cli::Opt;
+use crate::console::LogBuffer;
 use crate::custom_event::RuffleEvent;
-use crate::gui::{GuiController, MovieView, MENU_HEIGHT};
+use crate::custom_event::PlaybackCommand;
+use crate::gui::{GuiController, MovieSource, MENU_HEIGHT};
 use crate::player::PlayerController;
+use crate::preferences::{AutoplayPolicy, MovieKey, MovieSettings, Preferences};
 use crate::util::{
-    get_screen_size, parse_url, pick_file
+    get_screen_size, is_command_down, parse_url, pick_file, pick_files, pick_recording_dir,
 };
-use anyhow::{Context, Error};
+use anyhow::{anyhow, Context, Error};
 use ruffle_render::backend::ViewportDimensions;
-use ruffle_render_wgpu::backend::WgpuRenderBackend;
+use std::borrow::Cow;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
+use url::Url;
 use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Size};
-use winit::event::{ElementState, KeyboardInput, ModifiersState, VirtualKeyCode, WindowEvent};
+use winit::event::{
+    ElementState, KeyboardInput, ModifiersState, StartCause, VirtualKeyCode, WindowEvent,
+};
 use winit::event_loop::{ControlFlow, EventLoop, EventLoopBuilder};
 use winit::window::{Fullscreen, Icon, Window, WindowBuilder};
 
+/// Longest that a movie's title can be in the window title, so that the app name still fits after it.
+const MAX_TITLE_CHARS: usize = 60;
+
 pub struct App {
     opt: Opt,
     window: Rc<Window>,
     event_loop: Option<EventLoop<RuffleEvent>>,
     gui: Arc<Mutex<GuiController>>,
     player: PlayerController,
+    preferences: Preferences,
+    /// The directory the last file was picked from, where the next file dialog starts.
+    last_open_directory: Option<PathBuf>,
+    /// The title of the current movie, if one is loaded.
+    movie_title: Option<String>,
+    /// Which entry of the playlist is playing, counting from 1, and out of how many.
+    playlist_position: Option<(usize, usize)>,
     min_window_size: LogicalSize<u32>,
     max_window_size: PhysicalSize<u32>,
 }
 
 impl App {
-    pub fn new(opt: Opt) -> Result<Self, Error> {
-        let movie_url = if let Some(path) = &opt.input_path {
-            Some(parse_url(path).context("Couldn't load specified path")?)
-        } else {
-            None
+    pub fn new(opt: Opt, log_buffer: LogBuffer) -> Result<Self, Error> {
+        let movie_source = match &opt.input_path {
+            Some(path) if path.as_os_str() == "-" => Some(MovieSource::Stdin),
+            Some(path) if path.is_file() => Some(MovieSource::Path(path.clone())),
+            Some(path) => Some(MovieSource::Url(
+                parse_url(path).context("Couldn't load specified path")?,
+            )),
+            // Something is being piped in, like `ruffle < movie.json`.
+            None if !std::io::stdin().is_terminal() => Some(MovieSource::Stdin),
+            None => None,
         };
 
         /*let icon_bytes = include_bytes!("../assets/favicon-32.rgba");
         let icon =
             Icon::from_rgba(icon_bytes.to_vec(), 32, 32).context("Couldn't load app icon")?;*/
 
+        let preferences = Preferences::load();
+
         let event_loop = EventLoopBuilder::with_user_event().build();
 
         let min_window_size = (16, MENU_HEIGHT + 16).into();
@@ -52,24 +78,44 @@ impl App {
             .build(&event_loop)?;
         let window = Rc::new(window);
 
-        let gui = GuiController::new(
+        let mut gui = GuiController::new(
             window.clone(),
             &event_loop,
             opt.trace_path(),
             opt.graphics.into(),
             opt.power.into(),
+            &preferences,
+            log_buffer,
         )?;
 
-        let mut player = PlayerController::new(
-            event_loop.create_proxy(),
-            window.clone(),
-            gui.descriptors().clone(),
-        );
+        let mut player =
+            PlayerController::new(event_loop.create_proxy(), gui.descriptors().clone());
+        player.set_quality(preferences.quality);
+        player.set_volume(if preferences.is_muted {
+            0.0
+        } else {
+            preferences.volume
+        });
+        gui.set_volume(preferences.volume, preferences.is_muted);
+        gui.set_quality(player.quality());
+        if opt.fullscreen || preferences.start_fullscreen {
+            // The saved geometry is what the window goes back to when leaving fullscreen, so it's kept as it is.
+            gui.enter_fullscreen();
+        } else if let Some(geometry) = preferences.window_geometry {
+            gui.restore_window_geometry(geometry);
+        }
+        // After the window is set up, so that leaving kiosk mode goes back to it.
+        if opt.kiosk {
+            gui.set_kiosk(true);
+        }
 
-        if let Some(movie_url) = movie_url {
-            player.create(&opt, movie_url, gui.create_movie_view());
+        if let Some(source) = movie_source {
+            gui.open_on_startup(source);
+        }
+        if preferences.check_for_updates {
+            gui.check_for_updates(true);
         }
-        
+
 
         Ok(Self {
             opt,
@@ -77,6 +123,10 @@ impl App {
             event_loop: Some(event_loop),
             gui: Arc::new(Mutex::new(gui)),
             player,
+            preferences,
+            last_open_directory: None,
+            movie_title: None,
+            playlist_position: None,
             min_window_size,
             max_window_size,
         })
@@ -95,6 +145,10 @@ impl App {
         let mut minimized = false;
         let mut modifiers = ModifiersState::empty();
         let mut fullscreen_down = false;
+        // Where the pointer last was while the zoomed-in stage is being dragged around.
+        let mut drag_from: Option<PhysicalPosition<f64>> = None;
+        // When a redraw was held back to stay under the frame rate limit, which is when it's drawn instead.
+        let mut throttled_until: Option<Instant> = None;
 
         //if self.opt.input_path.is_none() {
             // No SWF provided on command line; show window with dummy movie immediately.
@@ -115,8 +169,23 @@ impl App {
                     return;
                 }
 
+                winit::event::Event::NewEvents(StartCause::ResumeTimeReached { .. }) => {
+                    if throttled_until.take().is_some() {
+                        self.window.request_redraw();
+                    }
+                }
+
                 // Core loop
-                winit::event::Event::MainEventsCleared => (),
+                winit::event::Event::MainEventsCleared => {
+                    let new_time = Instant::now();
+                    if self.player.tick(new_time.duration_since(time)) {
+                        self.window.request_redraw();
+                    }
+                    time = new_time;
+                    self.gui.lock().expect("Gui lock").reload_changed_file();
+                    #[cfg(feature = "gamepad")]
+                    self.gui.lock().expect("Gui lock").poll_gamepads();
+                }
                 /*    if matches!(loaded, LoadingState::Loaded) =>
                 {
                     println!("Doing frame loop");
@@ -136,19 +205,18 @@ impl App {
                 winit::event::Event::RedrawRequested(_) => {
                     // Don't render when minimized to avoid potential swap chain errors in `wgpu`.
                     if !minimized {
-                        if let Some(mut player) = self.player.get() {
+                        // Drawing now would go over the frame rate limit, so wait until the frame is due.
+                        if let Some(due_at) = self.gui.lock().expect("Gui lock").throttled_until() {
+                            throttled_until = Some(due_at);
+                            *control_flow = ControlFlow::WaitUntil(due_at);
+                            return;
+                        }
+                        let mut player = self.player.get();
+                        if let Some(player) = player.as_deref_mut() {
+                            player.set_fullscreen(self.window.fullscreen().is_some());
                             player.render();
-                            let renderer = player
-                                .renderer_mut()
-                                .downcast_mut::<WgpuRenderBackend<MovieView>>()
-                                .expect("Renderer must be correct type");
-                            self.gui
-                                .lock()
-                                .expect("Gui lock")
-                                .render(Some(renderer.target()));
-                        } else {
-                            self.gui.lock().expect("Gui lock").render(None);
                         }
+                        self.gui.lock().expect("Gui lock").render(player);
                         #[cfg(feature = "tracy")]
                         tracing_tracy::client::Client::running()
                             .expect("tracy client must be running")
@@ -157,17 +225,23 @@ impl App {
                 }
 
                 winit::event::Event::WindowEvent { event, .. } => {
+                    // The drag ends wherever the button is let go, even over the GUI.
+                    if let WindowEvent::MouseInput {
+                        button: winit::event::MouseButton::Left,
+                        state: ElementState::Released,
+                        ..
+                    } = &event
+                    {
+                        drag_from = None;
+                    }
                     if self.gui.lock().expect("Gui lock").handle_event(&event) {
                         // Event consumed by GUI.
                         return;
                     }
-                    let height_offset = if self.window.fullscreen().is_some() {
-                        0
-                    } else {
-                        MENU_HEIGHT
-                    };
+                    let height_offset = self.gui.lock().expect("Gui lock").menu_height();
                     match event {
                         WindowEvent::CloseRequested => {
+                            self.save_window_geometry();
                             *control_flow = ControlFlow::Exit;
                             return;
                         }
@@ -189,9 +263,18 @@ impl App {
                             }
                         }
                         WindowEvent::CursorMoved { position, .. } => {
-                            /*if self.gui.lock().expect("Gui lock").is_context_menu_visible() {
+                            mouse_pos = position;
+                            if self.gui.lock().expect("Gui lock").is_context_menu_visible() {
                                 return;
-                            }*/
+                            }
+
+                            if let Some(from) = drag_from {
+                                drag_from = Some(position);
+                                if let Some(mut player) = self.player.get() {
+                                    player.scroll_by(position.x - from.x, position.y - from.y);
+                                    self.window.request_redraw();
+                                }
+                            }
 
                             /*if let Some(mut player) = self.player.get() {
                                 mouse_pos = position;
@@ -204,12 +287,30 @@ impl App {
                             check_redraw = true;
                         }
                         WindowEvent::MouseInput { button, state, .. } => {
-                            /*if self.gui.lock().expect("Gui lock").is_context_menu_visible() {
+                            if self.gui.lock().expect("Gui lock").is_context_menu_visible() {
                                 return;
-                            }*/
+                            }
 
-                            /*use ruffle_core::events::MouseButton as RuffleMouseButton;
                             use winit::event::MouseButton;
+                            if let Some(mut player) = self.player.get() {
+                                if state == ElementState::Pressed && button == MouseButton::Right {
+                                    // Show context menu.
+                                    let context_menu = player.prepare_context_menu();
+                                    self.gui
+                                        .lock()
+                                        .expect("Gui lock")
+                                        .show_context_menu(context_menu);
+                                }
+                                // A stage that's zoomed in past the window can be dragged around to see the rest of it.
+                                if state == ElementState::Pressed
+                                    && button == MouseButton::Left
+                                    && player.can_scroll()
+                                {
+                                    drag_from = Some(mouse_pos);
+                                }
+                            }
+
+                            /*use ruffle_core::events::MouseButton as RuffleMouseButton;
                             if let Some(mut player) = self.player.get() {
                                 let x = mouse_pos.x;
                                 let y = mouse_pos.y - height_offset as f64;
@@ -225,36 +326,20 @@ impl App {
                                     }
                                     ElementState::Released => PlayerEvent::MouseUp { x, y, button },
                                 };
-                                if state == ElementState::Pressed
-                                    && button == RuffleMouseButton::Right
-                                {
-                                    // Show context menu.
-                                    // TODO: Should be squelched if player consumes the right click event.
-                                    let context_menu = player.prepare_context_menu();
-                                    self.gui
-                                        .lock()
-                                        .expect("Gui lock")
-                                        .show_context_menu(context_menu);
-                                }
                                 player.handle_event(event);
                             }*/
                             check_redraw = true;
                         }
                         WindowEvent::MouseWheel { delta, .. } => {
-                            /*use ruffle_core::events::MouseWheelDelta;
-                            use winit::event::MouseScrollDelta;
-                            if let Some(mut player) = self.player.get() {
-                                let delta = match delta {
-                                    MouseScrollDelta::LineDelta(_, dy) => {
-                                        MouseWheelDelta::Lines(dy.into())
-                                    }
-                                    MouseScrollDelta::PixelDelta(pos) => {
-                                        MouseWheelDelta::Pixels(pos.y)
-                                    }
-                                };
-                                let event = PlayerEvent::MouseWheel { delta };
-                                player.handle_event(event);
-                            }*/
+                            let mut player = self.player.get();
+                            self.gui
+                                .lock()
+                                .expect("Gui lock")
+                                .mouse_wheel(
+                                    delta,
+                                    is_command_down(modifiers),
+                                    player.as_deref_mut(),
+                                );
                             check_redraw = true;
                         }
                         WindowEvent::CursorEntered { .. } => {
@@ -275,6 +360,9 @@ impl App {
                         WindowEvent::ModifiersChanged(new_modifiers) => {
                             modifiers = new_modifiers;
                         }
+                        // Kiosk mode shouldn't offer any way to navigate away from the movie.
+                        WindowEvent::KeyboardInput { .. }
+                            if self.gui.lock().expect("Gui lock").is_kiosk() => {}
                         WindowEvent::KeyboardInput { input, .. } => {
                             // Handle fullscreen keyboard shortcuts: Alt+Return, Escape.
                             match input {
@@ -284,11 +372,7 @@ impl App {
                                     ..
                                 } if modifiers.alt() => {
                                     if !fullscreen_down {
-                                        /*if let Some(mut player) = self.player.get() {
-                                            player.update(|uc| {
-                                                uc.stage.toggle_display_state(uc);
-                                            });
-                                        }*/
+                                        self.toggle_fullscreen();
                                     }
                                     fullscreen_down = true;
                                     return;
@@ -300,6 +384,43 @@ impl App {
                                 } if fullscreen_down => {
                                     fullscreen_down = false;
                                 }
+                                // egui doesn't know about the comma key, so it can't be a menu shortcut.
+                                KeyboardInput {
+                                    state: ElementState::Pressed,
+                                    virtual_keycode: Some(VirtualKeyCode::Comma),
+                                    ..
+                                } if is_command_down(modifiers) => {
+                                    self.gui.lock().expect("Gui lock").show_preferences();
+                                    return;
+                                }
+                                // egui doesn't know about the bracket keys, so they can't be menu shortcuts.
+                                KeyboardInput {
+                                    state: ElementState::Pressed,
+                                    virtual_keycode:
+                                        Some(key @ (VirtualKeyCode::LBracket | VirtualKeyCode::RBracket)),
+                                    ..
+                                } if !self.gui.lock().expect("Gui lock").wants_keyboard_input() => {
+                                    let command = if key == VirtualKeyCode::LBracket {
+                                        PlaybackCommand::StepBackward
+                                    } else {
+                                        PlaybackCommand::StepForward
+                                    };
+                                    self.gui
+                                        .lock()
+                                        .expect("Gui lock")
+                                        .send_playback_command(command);
+                                    return;
+                                }
+                                // egui doesn't know about the Print Screen key, so it can't be rebound,
+                                // and always takes a screenshot on top of the rebindable shortcut.
+                                KeyboardInput {
+                                    state: ElementState::Pressed,
+                                    virtual_keycode: Some(VirtualKeyCode::Snapshot),
+                                    ..
+                                } => {
+                                    self.take_screenshot();
+                                    return;
+                                }
                                 /*KeyboardInput {
                                     state: ElementState::Pressed,
                                     virtual_keycode: Some(VirtualKeyCode::Escape),
@@ -430,37 +551,446 @@ impl App {
                     }
                 }*/
 
+                winit::event::Event::UserEvent(RuffleEvent::MovieMetadata(metadata)) => {
+                    self.gui
+                        .lock()
+                        .expect("Gui lock")
+                        .set_movie_metadata(Some(metadata));
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::Warning(message)) => {
+                    self.gui.lock().expect("Gui lock").add_warning(message);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::MovieTitleChanged(title)) => {
+                    self.movie_title = title;
+                    self.update_title();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::PlaylistPositionChanged(position)) => {
+                    self.playlist_position = position;
+                    self.update_title();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::MoviePlaybackEnded) => {
+                    self.gui.lock().expect("Gui lock").movie_playback_ended();
+                }
+
                 winit::event::Event::UserEvent(RuffleEvent::ContextMenuItemClicked(index)) => {
-                    /*if let Some(mut player) = self.player.get() {
+                    if let Some(mut player) = self.player.get() {
                         player.run_context_menu_callback(index);
-                    }*/
+                        self.window.request_redraw();
+                    }
                 }
 
-                winit::event::Event::UserEvent(RuffleEvent::OpenFile) => {
-                    if let Some(path) = pick_file() {
-                        // TODO: Show dialog on error.
-                        let url = parse_url(&path).expect("Couldn't load specified path");
-                        self.player.create(
-                            &self.opt,
-                            url,
-                            self.gui.lock().expect("Gui lock").create_movie_view(),
-                        );
+                winit::event::Event::UserEvent(RuffleEvent::OpenFileWithDialog) => {
+                    if let Some(path) = pick_file(self.last_open_directory.as_deref()) {
+                        self.last_open_directory = path.parent().map(Path::to_path_buf);
+                        self.open_file(path);
                     }
                 }
 
-                winit::event::Event::UserEvent(RuffleEvent::OpenURL(url)) => {
-                    self.player.create(
-                        &self.opt,
-                        url,
-                        self.gui.lock().expect("Gui lock").create_movie_view(),
-                    );
+                winit::event::Event::UserEvent(RuffleEvent::OpenFileInNewWindowWithDialog) => {
+                    if let Some(path) = pick_file(self.last_open_directory.as_deref()) {
+                        self.last_open_directory = path.parent().map(Path::to_path_buf);
+                        self.open_file_in_new_window(path);
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::AddToPlaylistWithDialog) => {
+                    if let Some(paths) = pick_files(self.last_open_directory.as_deref()) {
+                        if let Some(path) = paths.first() {
+                            self.last_open_directory = path.parent().map(Path::to_path_buf);
+                        }
+                        let urls = paths
+                            .iter()
+                            .filter_map(|path| parse_url(path).ok())
+                            .collect();
+                        self.gui.lock().expect("Gui lock").add_to_playlist(urls);
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::OpenFile(path))
+                | winit::event::Event::UserEvent(RuffleEvent::OpenRecent(path))
+                | winit::event::Event::UserEvent(RuffleEvent::OpenDroppedFile(path)) => {
+                    self.open_file(path);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::OpenURL(url, parameters)) => {
+                    self.open_url(url, parameters);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::OpenBytes { name, data }) => {
+                    self.open_bytes(name, &data);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::Reload) => {
+                    if let Some(url) = self.player.movie_url().cloned() {
+                        let parameters = self.player.movie_parameters().to_vec();
+                        self.open_url(url, parameters);
+                    }
                 }
 
                 winit::event::Event::UserEvent(RuffleEvent::CloseFile) => {
                     self.player.destroy();
+                    let mut gui = self.gui.lock().expect("Gui lock");
+                    gui.set_movie_url(None);
+                    gui.set_movie_metadata(None);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetWatchFile(watch_file)) => {
+                    self.gui.lock().expect("Gui lock").set_watch_file(watch_file);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::WatchedFileChanged) => {
+                    self.gui.lock().expect("Gui lock").watched_file_changed();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::PlaybackControl(command)) => {
+                    if let Some(mut player) = self.player.get() {
+                        match command {
+                            PlaybackCommand::Play => player.play(),
+                            PlaybackCommand::Pause => player.pause(),
+                            // Stepping while playing would be overtaken by the next frame straight away.
+                            PlaybackCommand::StepForward => {
+                                player.pause();
+                                player.step_forward();
+                            }
+                            PlaybackCommand::StepBackward => {
+                                player.pause();
+                                player.step_backward();
+                            }
+                            PlaybackCommand::Rewind => player.seek(0),
+                        }
+                        self.window.request_redraw();
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SeekToFrame(frame)) => {
+                    if let Some(mut player) = self.player.get() {
+                        let frame = u16::try_from(frame.saturating_sub(1)).unwrap_or(u16::MAX);
+                        player.seek(frame);
+                        self.window.request_redraw();
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::ScrollTo(x, y)) => {
+                    if let Some(mut player) = self.player.get() {
+                        player.scroll_to(x, y);
+                        self.window.request_redraw();
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetVolume { volume, is_muted }) => {
+                    self.player.set_volume(if is_muted { 0.0 } else { volume });
+                    match self.movie_settings() {
+                        Some(settings) => settings.volume = Some(volume),
+                        None => {
+                            self.preferences.volume = volume;
+                            self.gui
+                                .lock()
+                                .expect("Gui lock")
+                                .set_defaults(volume, self.preferences.quality);
+                        }
+                    }
+                    self.preferences.is_muted = is_muted;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetQuality(quality)) => {
+                    self.player.set_quality(quality);
+                    self.window.request_redraw();
+                    match self.movie_settings() {
+                        Some(settings) => settings.quality = Some(quality),
+                        None => {
+                            self.preferences.quality = quality;
+                            self.gui
+                                .lock()
+                                .expect("Gui lock")
+                                .set_defaults(self.preferences.volume, quality);
+                        }
+                    }
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetDefaultVolume { volume, is_muted }) => {
+                    self.preferences.volume = volume;
+                    self.preferences.is_muted = is_muted;
+                    self.preferences.save();
+                    // A movie that has its own volume keeps it, but muting goes for every movie.
+                    let volume = self
+                        .saved_movie_settings()
+                        .and_then(|settings| settings.volume)
+                        .unwrap_or(volume);
+                    self.player.set_volume(if is_muted { 0.0 } else { volume });
+                    self.gui.lock().expect("Gui lock").set_volume(volume, is_muted);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetDefaultQuality(quality)) => {
+                    self.preferences.quality = quality;
+                    self.preferences.save();
+                    if self.saved_movie_settings().and_then(|settings| settings.quality).is_none() {
+                        self.player.set_quality(quality);
+                        self.gui.lock().expect("Gui lock").set_quality(quality);
+                        self.window.request_redraw();
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetScaleMode(scale_mode)) => {
+                    self.player.set_scale_mode(scale_mode);
+                    self.window.request_redraw();
+                    if let Some(settings) = self.movie_settings() {
+                        settings.scale_mode = Some(scale_mode);
+                        self.preferences.save();
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::ResetMovieSettings) => {
+                    if let Some(url) = self.player.movie_url() {
+                        self.preferences.movie_settings.remove(&MovieKey::new(url));
+                        self.preferences.save();
+                    }
+                    let mut gui = self.gui.lock().expect("Gui lock");
+                    let is_muted = gui.is_muted();
+                    Self::apply_movie_settings(&self.preferences, &mut self.player, &mut gui, is_muted);
+                    self.window.request_redraw();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetForceScaleMode(force_scale_mode)) => {
+                    self.player.set_force_scale_mode(force_scale_mode);
+                    self.window.request_redraw();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::ClipboardOp(command)) => {
+                    if let Some(mut player) = self.player.get() {
+                        self.gui
+                            .lock()
+                            .expect("Gui lock")
+                            .clipboard_op(&mut player, command);
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::TakeScreenshot) => {
+                    self.take_screenshot();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::CopyFrame) => {
+                    if let Some(mut player) = self.player.get() {
+                        self.gui.lock().expect("Gui lock").copy_frame(&mut player);
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::PickRecordingDir) => {
+                    if let Some(dir) = pick_recording_dir() {
+                        self.gui.lock().expect("Gui lock").set_recording_dir(dir);
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::RecordFrames { count, dir }) => {
+                    if let Some(mut player) = self.player.get() {
+                        self.gui
+                            .lock()
+                            .expect("Gui lock")
+                            .record_frames(&mut player, count, dir);
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::CancelRecording) => {
+                    let mut player = self.player.get();
+                    self.gui
+                        .lock()
+                        .expect("Gui lock")
+                        .stop_recording(player.as_deref_mut(), Ok(()));
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetZoom(zoom)) => {
+                    self.player.set_zoom(zoom);
+                    self.window.request_redraw();
+                    if let Some(settings) = self.movie_settings() {
+                        settings.zoom = Some(zoom);
+                        self.preferences.save();
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetLetterbox(letterbox)) => {
+                    self.player.set_letterbox(letterbox);
+                    self.window.request_redraw();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetLetterboxColor(color)) => {
+                    self.player.set_letterbox_color(swf::Color {
+                        r: color.r(),
+                        g: color.g(),
+                        b: color.b(),
+                        a: 255,
+                    });
+                    self.window.request_redraw();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::MatchWindowSize) => {
+                    if let Some(player) = self.player.get() {
+                        let metadata = player.metadata();
+                        self.gui
+                            .lock()
+                            .expect("Gui lock")
+                            .match_window_to_movie(metadata.width, metadata.height);
+                    }
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::ToggleFullscreen) => {
+                    self.toggle_fullscreen();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetPauseOnUnfocus(pause_on_unfocus)) => {
+                    self.preferences.pause_on_unfocus = pause_on_unfocus;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetUiScale(ui_scale)) => {
+                    self.preferences.ui_scale = ui_scale;
+                    self.preferences.save();
+                    self.window.request_redraw();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetForwardMouseWheel(forward_mouse_wheel)) => {
+                    self.preferences.forward_mouse_wheel = forward_mouse_wheel;
+                    self.preferences.save();
+                    self.gui
+                        .lock()
+                        .expect("Gui lock")
+                        .set_forward_mouse_wheel(forward_mouse_wheel);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetAutoHideMenu(auto_hide_menu)) => {
+                    self.preferences.auto_hide_menu = auto_hide_menu;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetHideMenuBar(hide_menu_bar)) => {
+                    self.preferences.hide_menu_bar = hide_menu_bar;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetAutoplayPolicy(autoplay)) => {
+                    self.preferences.autoplay = autoplay;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetBookmarks(bookmarks)) => {
+                    self.preferences.bookmarks = bookmarks;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetReduceMotion(reduce_motion)) => {
+                    self.preferences.reduce_motion = reduce_motion;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SystemTheme(theme)) => {
+                    self.gui.lock().expect("Gui lock").set_system_theme(theme);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SystemReducesMotion(reduces_motion)) => {
+                    self.gui
+                        .lock()
+                        .expect("Gui lock")
+                        .set_system_reduces_motion(reduces_motion);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetTheme(theme)) => {
+                    self.preferences.theme = theme;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetMaxFps(max_fps)) => {
+                    self.preferences.max_fps = max_fps;
+                    self.preferences.save();
+                    self.gui.lock().expect("Gui lock").set_max_fps(max_fps);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetKiosk(kiosk)) => {
+                    self.gui.lock().expect("Gui lock").set_kiosk(kiosk);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::UpdateCheckResult(result)) => {
+                    self.gui
+                        .lock()
+                        .expect("Gui lock")
+                        .update_check_finished(result);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetCheckForUpdates(check_for_updates)) => {
+                    self.preferences.check_for_updates = check_for_updates;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetAlwaysOnTop(always_on_top)) => {
+                    self.preferences.always_on_top = always_on_top;
+                    self.preferences.save();
+                    self.gui
+                        .lock()
+                        .expect("Gui lock")
+                        .set_always_on_top(always_on_top);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetStartFullscreen(start_fullscreen)) => {
+                    self.preferences.start_fullscreen = start_fullscreen;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetConfirmExit(confirm_exit)) => {
+                    self.preferences.confirm_exit = confirm_exit;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetRecentFiles(recent_files)) => {
+                    self.preferences.recent_files = recent_files;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetUrlHistory(url_history)) => {
+                    self.preferences.url_history = url_history;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetSilencedWarnings(urls)) => {
+                    self.preferences.silenced_warnings = urls;
+                    self.preferences.save();
+                }
+
+                #[cfg(feature = "gamepad")]
+                winit::event::Event::UserEvent(RuffleEvent::SetGamepadMapping(mapping)) => {
+                    self.preferences.gamepad_mapping = mapping.clone();
+                    self.preferences.save();
+                    self.gui.lock().expect("Gui lock").set_gamepad_mapping(mapping);
+                }
+
+                #[cfg(feature = "gamepad")]
+                winit::event::Event::UserEvent(RuffleEvent::GamepadConnected(name)) => {
+                    self.gui.lock().expect("Gui lock").gamepad_connected(name, true);
+                }
+
+                #[cfg(feature = "gamepad")]
+                winit::event::Event::UserEvent(RuffleEvent::GamepadDisconnected(name)) => {
+                    self.gui.lock().expect("Gui lock").gamepad_connected(name, false);
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetShortcuts(shortcuts)) => {
+                    self.preferences.shortcuts = shortcuts;
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SetLanguage(language)) => {
+                    self.preferences.language = language.map(|language| language.to_string());
+                    self.preferences.save();
+                }
+
+                winit::event::Event::UserEvent(RuffleEvent::SaveConsoleLog(text)) => {
+                    self.gui.lock().expect("Gui lock").save_console_log(&text);
                 }
 
                 winit::event::Event::UserEvent(RuffleEvent::ExitRequested) => {
+                    self.save_window_geometry();
                     *control_flow = ControlFlow::Exit;
                     return;
                 }
@@ -483,7 +1013,214 @@ impl App {
             } else {
                 ControlFlow::Wait
             };*/
-            *control_flow = ControlFlow::Wait;
+            let mut wake_at = throttled_until;
+            if let Some(frame_at) = self.player.next_frame_time(time) {
+                wake_at = Some(wake_at.map_or(frame_at, |wake_at| wake_at.min(frame_at)));
+            }
+            if let Some(reload_at) = self.gui.lock().expect("Gui lock").next_watched_file_reload() {
+                wake_at = Some(wake_at.map_or(reload_at, |wake_at| wake_at.min(reload_at)));
+            }
+            #[cfg(feature = "gamepad")]
+            if let Some(poll_at) = self.gui.lock().expect("Gui lock").next_gamepad_poll() {
+                wake_at = Some(wake_at.map_or(poll_at, |wake_at| wake_at.min(poll_at)));
+            }
+            *control_flow = match wake_at {
+                Some(wake_at) => ControlFlow::WaitUntil(wake_at),
+                None => ControlFlow::Wait,
+            };
         });
     }
+
+    fn open_url(&mut self, url: Url, parameters: Vec<(String, String)>) {
+        let mut gui = self.gui.lock().expect("Gui lock");
+        match self
+            .player
+            .create(&self.opt, url.clone(), parameters, gui.create_movie_view())
+        {
+            Ok(()) => Self::start_movie(&self.preferences, &mut self.player, &mut gui),
+            Err(e) => {
+                tracing::error!("Couldn't load {url}: {e:#}");
+                gui.show_load_error(url.to_string(), format!("{e:#}"));
+            }
+        }
+    }
+
+    /// Names the window after the current movie, along with where it is in the playlist if it's from there.
+    fn update_title(&self) {
+        let movie_title = self.movie_title.as_deref().map(truncate_title);
+        let title = match (movie_title, self.playlist_position) {
+            (Some(title), Some((position, count))) => {
+                format!("{title} ({position}/{count}) — Ruffle")
+            }
+            (Some(title), None) => format!("{title} — Ruffle"),
+            (None, _) => "Ruffle".to_string(),
+        };
+        self.window.set_title(&title);
+    }
+
+    /// Opens a movie that's already in memory. It has nowhere to be reopened from, so it isn't added to the
+    /// recent files.
+    fn open_bytes(&mut self, name: String, data: &[u8]) {
+        let mut gui = self.gui.lock().expect("Gui lock");
+        match self
+            .player
+            .create_from_bytes(name.clone(), data, gui.create_movie_view())
+        {
+            Ok(()) => Self::start_movie(&self.preferences, &mut self.player, &mut gui),
+            Err(e) => {
+                tracing::error!("Couldn't load {name}: {e:#}");
+                gui.show_load_error(name, format!("{e:#}"));
+            }
+        }
+    }
+
+    fn open_file(&mut self, path: PathBuf) {
+        let mut gui = self.gui.lock().expect("Gui lock");
+        let result = if path.is_file() {
+            parse_url(&path)
+                .and_then(|url| {
+                    self.player
+                        .create(&self.opt, url, vec![], gui.create_movie_view())
+                })
+        } else {
+            Err(anyhow!("File doesn't exist"))
+        };
+        match result {
+            Ok(()) => {
+                Self::start_movie(&self.preferences, &mut self.player, &mut gui);
+                gui.add_recent_file(path);
+            }
+            Err(e) => {
+                tracing::error!("Couldn't load {}: {e:#}", path.display());
+                gui.show_load_error(path.display().to_string(), format!("{e:#}"));
+                if !path.exists() {
+                    gui.remove_recent_file(&path);
+                }
+            }
+        }
+    }
+
+    /// Opens the file in another instance of the player, so that the movie gets a window and player of its own.
+    /// It's started with the same command-line options as this one, and with the saved preferences rather than
+    /// this one's. Only the first instance saves changes to the preferences, see [`Preferences::save`].
+    fn open_file_in_new_window(&mut self, path: PathBuf) {
+        let mut gui = self.gui.lock().expect("Gui lock");
+        let result = std::env::current_exe().and_then(|exe| {
+            Command::new(exe)
+                .args(self.opt.forwarded_args())
+                .arg("--")
+                .arg(&path)
+                .stdin(Stdio::null())
+                .spawn()
+        });
+        match result {
+            Ok(mut child) => {
+                // Wait for it in the background, so that it doesn't linger as a zombie process once it's closed.
+                std::thread::spawn(move || match child.wait() {
+                    Ok(status) if !status.success() => tracing::warn!("The new window exited with {status}"),
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Couldn't wait for the new window to close: {e}"),
+                });
+                gui.add_recent_file(path);
+            }
+            Err(e) => {
+                tracing::error!("Couldn't open {} in a new window: {e}", path.display());
+                gui.show_load_error(path.display().to_string(), e.to_string());
+            }
+        }
+    }
+
+    /// Lets the GUI know about a freshly loaded movie, and starts it with its own settings, the way the autoplay
+    /// preference asks for. Muting only lasts for this movie, so it isn't saved to the preferences.
+    fn start_movie(preferences: &Preferences, player: &mut PlayerController, gui: &mut GuiController) {
+        gui.set_movie_url(player.movie_url().cloned());
+        let is_muted = preferences.is_muted || preferences.autoplay == AutoplayPolicy::Muted;
+        Self::apply_movie_settings(preferences, player, gui, is_muted);
+        let Some(mut player) = player.get() else {
+            return;
+        };
+        let is_paused = preferences.autoplay == AutoplayPolicy::Off;
+        if is_paused {
+            player.pause();
+        }
+        gui.set_play_overlay(is_paused);
+    }
+
+    /// Plays the loaded movie with the settings it was last played with,
+    /// and with the defaults for any that weren't changed while it was loaded.
+    fn apply_movie_settings(
+        preferences: &Preferences,
+        player: &mut PlayerController,
+        gui: &mut GuiController,
+        is_muted: bool,
+    ) {
+        let settings = player
+            .movie_url()
+            .and_then(|url| preferences.movie_settings.get(&MovieKey::new(url)))
+            .cloned()
+            .unwrap_or_default();
+        let quality = settings.quality.unwrap_or(preferences.quality);
+        let scale_mode = settings.scale_mode.unwrap_or_default();
+        let zoom = settings.zoom.unwrap_or(1.0);
+        let volume = settings.volume.unwrap_or(preferences.volume);
+        player.set_quality(quality);
+        player.set_scale_mode(scale_mode);
+        player.set_zoom(zoom);
+        player.set_volume(if is_muted { 0.0 } else { volume });
+        gui.set_movie_settings(quality, scale_mode, zoom);
+        gui.set_volume(volume, is_muted);
+    }
+
+    /// The settings remembered for the loaded movie, which any changes made while it's loaded go to.
+    /// Returns `None` if no movie is loaded, in which case changes go to the defaults instead.
+    fn movie_settings(&mut self) -> Option<&mut MovieSettings> {
+        let key = MovieKey::new(self.player.movie_url()?);
+        Some(self.preferences.movie_settings.entry(key).or_default())
+    }
+
+    /// The settings remembered for the loaded movie, without starting to remember any if there aren't.
+    fn saved_movie_settings(&self) -> Option<&MovieSettings> {
+        let key = MovieKey::new(self.player.movie_url()?);
+        self.preferences.movie_settings.get(&key)
+    }
+
+    /// Remembers the window's position and size, so the next session can start the same way.
+    fn save_window_geometry(&mut self) {
+        let geometry = self.gui.lock().expect("Gui lock").window_geometry();
+        if geometry.is_some() {
+            self.preferences.window_geometry = geometry;
+            self.preferences.save();
+        }
+    }
+
+    fn take_screenshot(&self) {
+        if let Some(mut player) = self.player.get() {
+            self.gui
+                .lock()
+                .expect("Gui lock")
+                .take_screenshot(&mut player);
+        }
+    }
+
+    fn toggle_fullscreen(&self) {
+        if self.window.fullscreen().is_some() {
+            self.window.set_fullscreen(None);
+            self.gui.lock().expect("Gui lock").restore_window_level();
+        } else {
+            self.window.set_fullscreen(Some(Fullscreen::Borderless(None)));
+        }
+    }
+}
+
+/// Shortens titles longer than [`MAX_TITLE_CHARS`] by cutting out their middle,
+/// where long file names tend to differ the least.
+fn truncate_title(title: &str) -> Cow<'_, str> {
+    let len = title.chars().count();
+    if len <= MAX_TITLE_CHARS {
+        return Cow::Borrowed(title);
+    }
+    let keep = MAX_TITLE_CHARS - 1;
+    let start: String = title.chars().take(keep - keep / 2).collect();
+    let end: String = title.chars().skip(len - keep / 2).collect();
+    Cow::Owned(format!("{start}…{end}"))
 }
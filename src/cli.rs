@@ -1,5 +1,5 @@
 use crate::RUFFLE_VERSION;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use ruffle_render::quality::StageQuality;
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
 use std::path::{Path, PathBuf};
@@ -13,6 +13,7 @@ use url::Url;
 )]
 pub struct Opt {
     /// Path or URL of a Flash movie (SWF) to play.
+    /// Use `-`, or leave it out while piping a movie in, to read the movie from standard input.
     #[clap(name = "FILE")]
     pub input_path: Option<PathBuf>,
 
@@ -39,6 +40,11 @@ pub struct Opt {
     #[clap(long, display_order = 2)]
     pub height: Option<f64>,
 
+    /// Start in kiosk mode, where the movie fills the screen without any menus or dialogs.
+    /// Press Ctrl+Shift+K (Cmd+Shift+K on macOS) to leave it.
+    #[clap(long, action)]
+    pub kiosk: bool,
+
     /*/// Maximum number of seconds a script can run before scripting is disabled.
     #[clap(long, short, default_value = "15.0")]
     pub max_execution_duration: f64,
@@ -128,6 +134,28 @@ impl Opt {
         None
     }
 
+    /// The options to start another instance of the player with, so that it runs the same way as this one.
+    /// The movie to play isn't included.
+    pub fn forwarded_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(graphics) = self.graphics.to_possible_value() {
+            args.push(format!("--graphics={}", graphics.get_name()));
+        }
+        if let Some(power) = self.power.to_possible_value() {
+            args.push(format!("--power={}", power.get_name()));
+        }
+        if let Some(width) = self.width {
+            args.push(format!("--width={width}"));
+        }
+        if let Some(height) = self.height {
+            args.push(format!("--height={height}"));
+        }
+        if self.kiosk {
+            args.push("--kiosk".to_string());
+        }
+        args
+    }
+
     /*pub fn parameters(&self) -> impl '_ + Iterator<Item = (String, String)> {
         self.parameters.iter().map(|parameter| {
             let mut split = parameter.splitn(2, '=');
@@ -139,3 +167,30 @@ impl Opt {
         })
     }*/
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forwarded_args_start_the_same_way() {
+        let opt = Opt::parse_from([
+            "rattles",
+            "--graphics=vulkan",
+            "--power=low",
+            "--width=640",
+            "--height=480.5",
+            "--kiosk",
+            "movie.json",
+        ]);
+        let forwarded = Opt::parse_from(
+            std::iter::once("rattles".to_string()).chain(opt.forwarded_args()),
+        );
+        assert_eq!(forwarded.graphics, opt.graphics);
+        assert_eq!(forwarded.power, opt.power);
+        assert_eq!(forwarded.width, Some(640.0));
+        assert_eq!(forwarded.height, Some(480.5));
+        assert!(forwarded.kiosk);
+        assert_eq!(forwarded.input_path, None);
+    }
+}
@@ -0,0 +1,113 @@
+//! Recent log messages, kept for the console in the GUI on top of being written to stderr
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Write};
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+/// How many lines the console keeps, dropping the oldest past that.
+pub const MAX_CONSOLE_LINES: usize = 5000;
+
+/// Where a console line came from, and how severe it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConsoleLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl ConsoleLevel {
+    fn from_tracing(level: Level) -> Self {
+        match level {
+            Level::ERROR => Self::Error,
+            Level::WARN => Self::Warn,
+            Level::INFO => Self::Info,
+            _ => Self::Debug,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ConsoleLine {
+    pub level: ConsoleLevel,
+    pub text: String,
+}
+
+/// Lines logged since the console last picked them up, shared between the logging layer and the GUI.
+/// Only the most recent ones are kept if the GUI doesn't keep up.
+#[derive(Clone, Default)]
+pub struct LogBuffer {
+    lines: Arc<Mutex<VecDeque<ConsoleLine>>>,
+}
+
+impl LogBuffer {
+    fn push(&self, line: ConsoleLine) {
+        // Logging mustn't panic, so lines are dropped if another thread panicked while holding the lock.
+        if let Ok(mut lines) = self.lines.lock() {
+            if lines.len() == MAX_CONSOLE_LINES {
+                lines.pop_front();
+            }
+            lines.push_back(line);
+        }
+    }
+
+    /// Takes the lines logged since this was last called, oldest first.
+    pub fn take(&self) -> Vec<ConsoleLine> {
+        self.lines
+            .lock()
+            .map(|mut lines| lines.drain(..).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Feeds `tracing` events into a [`LogBuffer`], formatted like they are on stderr.
+pub struct ConsoleLayer {
+    buffer: LogBuffer,
+}
+
+impl ConsoleLayer {
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self { buffer }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for ConsoleLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let text = format!(
+            "{} {:>5} {}: {}{}",
+            chrono::Local::now().format("%H:%M:%S%.3f"),
+            metadata.level(),
+            metadata.target(),
+            visitor.message,
+            visitor.fields
+        );
+        self.buffer.push(ConsoleLine {
+            level: ConsoleLevel::from_tracing(*metadata.level()),
+            text,
+        });
+    }
+}
+
+/// Collects an event's message, and any other fields after it as `name=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
@@ -1,6 +1,19 @@
 //! Custom event type for desktop ruffle
 
+use crate::editor::main::ScaleMode;
+use crate::editor::player::{Letterbox, MovieMetadata};
+use crate::preferences::{AutoplayPolicy, Bookmark, MaxFps, ReduceMotion, Theme};
+#[cfg(feature = "gamepad")]
+use crate::gamepad::GamepadMapping;
+use crate::shortcuts::Shortcuts;
+use crate::update::Release;
+use egui::Color32;
+use ruffle_render::quality::StageQuality;
+use std::path::PathBuf;
+use unic_langid::LanguageIdentifier;
+
 /// User-defined events.
+#[derive(Debug)]
 pub enum RuffleEvent {
     /// Indicates that one or more tasks are ready to poll on our executor.
     TaskPoll,
@@ -8,18 +21,248 @@ pub enum RuffleEvent {
     /// Indicates that an asynchronous SWF metadata load has been completed.
     //OnMetadata(ruffle_core::swf::HeaderExt),
 
-    /// The user requested to open a new local SWF.
-    OpenFile,
+    /// A movie was loaded, with the given metadata.
+    MovieMetadata(MovieMetadata),
+
+    /// The current movie uses something that the player can't handle, as described by the message.
+    Warning(String),
+
+    /// The title of the current movie changed, or `None` if no movie is loaded anymore.
+    MovieTitleChanged(Option<String>),
+
+    /// The user requested to pick a local SWF to open.
+    OpenFileWithDialog,
+
+    /// The user requested to pick a local SWF to open in a new window, leaving this one's movie playing.
+    OpenFileInNewWindowWithDialog,
+
+    /// Open the given local file, without asking the user first.
+    OpenFile(PathBuf),
+
+    /// The user requested to open a file from the recent files list.
+    OpenRecent(PathBuf),
+
+    /// The user dropped a file onto the window.
+    OpenDroppedFile(PathBuf),
+
+    /// A movie was read into memory, such as from standard input, and should be opened under the given name.
+    OpenBytes { name: String, data: Vec<u8> },
+
+    /// The user requested to open a URL, with extra parameters ("flashvars") for the movie.
+    OpenURL(url::Url, Vec<(String, String)>),
 
-    /// The user requested to open a URL.
-    OpenURL(url::Url),
+    /// The user requested to load the current movie again from where it came from.
+    Reload,
+
+    /// The user requested to pick local files to add to the end of the playlist.
+    AddToPlaylistWithDialog,
+
+    /// The movie being played is now the given entry of the playlist, counting from 1, out of how many entries
+    /// there are. `None` if it isn't from the playlist.
+    PlaylistPositionChanged(Option<(usize, usize)>),
+
+    /// The current movie reached its end and stopped, so the next one in the playlist can be played.
+    MoviePlaybackEnded,
 
     /// The user requested to close the current SWF.
     CloseFile,
 
+    /// The user requested a change in playback of the current SWF.
+    PlaybackControl(PlaybackCommand),
+
+    /// The user requested to jump to the given frame, counting from 1 like Flash does.
+    SeekToFrame(u32),
+
+    /// The user dragged a scrollbar of the zoomed-in stage, to the given fraction of the way along it
+    /// horizontally and vertically.
+    ScrollTo(f64, f64),
+
+    /// The user changed the audio volume, as a number between 0 and 1, or muted or unmuted audio.
+    /// The volume is remembered for the loaded movie, or becomes the default if none is loaded.
+    SetVolume { volume: f32, is_muted: bool },
+
+    /// The user changed the rendering quality.
+    /// The quality is remembered for the loaded movie, or becomes the default if none is loaded.
+    SetQuality(StageQuality),
+
+    /// The user changed the volume that movies play at unless they have their own, or muted or unmuted audio.
+    SetDefaultVolume { volume: f32, is_muted: bool },
+
+    /// The user changed the quality that movies are rendered at unless they have their own.
+    SetDefaultQuality(StageQuality),
+
+    /// The user picked a scale mode for the stage.
+    SetScaleMode(ScaleMode),
+
+    /// The user toggled whether their scale mode overrides the one requested by the movie.
+    SetForceScaleMode(bool),
+
+    /// The user requested to save the current frame as an image.
+    TakeScreenshot,
+
+    /// The user requested to copy the current frame to the clipboard as an image.
+    CopyFrame,
+
+    /// The user requested to pick the directory that recorded frames are saved in.
+    PickRecordingDir,
+
+    /// The user asked for the given number of frames, starting at the current one,
+    /// to be saved as numbered PNGs in a directory.
+    RecordFrames { count: u32, dir: PathBuf },
+
+    /// The user cancelled the frames being recorded.
+    CancelRecording,
+
+    /// The user changed how much the movie is magnified, where 1 is its normal size.
+    SetZoom(f32),
+
+    /// The user changed when the area around the stage is covered up.
+    SetLetterbox(Letterbox),
+
+    /// The user changed the color that the area around the stage is covered with.
+    SetLetterboxColor(Color32),
+
+    /// The user requested to resize the window to fit the movie's stage.
+    MatchWindowSize,
+
+    /// The user requested to enter or leave fullscreen.
+    ToggleFullscreen,
+
+    /// The user toggled whether the movie is paused while the window is unfocused.
+    SetPauseOnUnfocus(bool),
+
+    /// The user changed how large menus and dialogs are drawn.
+    SetUiScale(f32),
+
+    /// The user chose whether the GUI's animations are turned off.
+    SetReduceMotion(ReduceMotion),
+
+    /// Whether the system asks for animations to be reduced, once that's been read in the background.
+    SystemReducesMotion(bool),
+
+    /// The system's light or dark preference, once that's been read in the background
+    /// on platforms where winit can't tell it.
+    SystemTheme(winit::window::Theme),
+
+    /// The user toggled whether scrolling goes to the movie, rather than zooming the view.
+    SetForwardMouseWheel(bool),
+
+    /// The user asked to forget the settings remembered for the loaded movie.
+    ResetMovieSettings,
+
+    /// The user toggled whether the loaded movie is reloaded whenever its file changes.
+    SetWatchFile(bool),
+
+    /// The file of the loaded movie changed on disk while it was being watched.
+    WatchedFileChanged,
+
+    /// The user toggled whether the menu bar is hidden in fullscreen.
+    SetAutoHideMenu(bool),
+
+    /// The user toggled whether the menu bar is hidden in a window too.
+    SetHideMenuBar(bool),
+
+    /// The user toggled whether the window goes fullscreen as soon as Ruffle starts.
+    SetStartFullscreen(bool),
+
+    /// The user toggled whether the window is kept above other windows.
+    SetAlwaysOnTop(bool),
+
+    /// The user changed how movies start out when they're loaded.
+    SetAutoplayPolicy(AutoplayPolicy),
+
+    /// The user added, renamed, removed or reordered bookmarks.
+    SetBookmarks(Vec<Bookmark>),
+
+    /// The user picked a color scheme for the GUI.
+    SetTheme(Theme),
+
+    /// The user limited how often the window is redrawn.
+    SetMaxFps(MaxFps),
+
+    /// The user entered or left kiosk mode.
+    SetKiosk(bool),
+
+    /// The user toggled whether exiting with a movie loaded has to be confirmed.
+    SetConfirmExit(bool),
+
+    /// The files in the "Open Recent" menu changed, most recent first.
+    SetRecentFiles(Vec<PathBuf>),
+
+    /// The history of the Open URL prompt changed, most recent first.
+    SetUrlHistory(Vec<String>),
+
+    /// The user asked not to be warned about the given movies anymore, by URL.
+    SetSilencedWarnings(Vec<String>),
+
+    /// The user rebound a keyboard shortcut.
+    SetShortcuts(Shortcuts),
+
+    /// The user changed which keys gamepad buttons press.
+    #[cfg(feature = "gamepad")]
+    SetGamepadMapping(GamepadMapping),
+
+    /// A gamepad with the given name was plugged in.
+    #[cfg(feature = "gamepad")]
+    GamepadConnected(String),
+
+    /// A gamepad with the given name was unplugged.
+    #[cfg(feature = "gamepad")]
+    GamepadDisconnected(String),
+
+    /// The user picked a language for the GUI, or `None` to follow the system locale.
+    SetLanguage(Option<LanguageIdentifier>),
+
+    /// The user asked to save the lines shown in the console to a file.
+    SaveConsoleLog(String),
+
+    /// Checking for a newer release finished, finding the given release or `None` if this build is up to date.
+    UpdateCheckResult(anyhow::Result<Option<Release>>),
+
+    /// The user toggled whether to check for updates when Ruffle starts.
+    SetCheckForUpdates(bool),
+
     /// The user requested to exit Ruffle.
     ExitRequested,
 
     /// The user selected an item in the right-click context menu.
     ContextMenuItemClicked(usize),
+
+    /// The user picked a clipboard action from the Edit menu.
+    ClipboardOp(ClipboardCommand),
+}
+
+/// Playback actions available from the Controls menu.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlaybackCommand {
+    /// Resume playback.
+    Play,
+
+    /// Stop playback on the current frame.
+    Pause,
+
+    /// Advance the timeline by a single frame.
+    StepForward,
+
+    /// Move the timeline back by a single frame.
+    StepBackward,
+
+    /// Go back to the first frame, without changing whether it's playing.
+    Rewind,
+}
+
+/// Clipboard actions available from the Edit menu, applied to the movie's focused text field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClipboardCommand {
+    /// Move the selected text to the clipboard.
+    Cut,
+
+    /// Copy the selected text to the clipboard.
+    Copy,
+
+    /// Replace the selection with the text on the clipboard.
+    Paste,
+
+    /// Select all of the text.
+    SelectAll,
 }
@@ -4,6 +4,10 @@ use ruffle_render::bitmap::BitmapHandle;
 use swf::*;
 use serde::{Deserialize, Serialize};
 use image::{io::Reader as ImageReader, EncodableLayout, DynamicImage};
+use anyhow::Context;
+
+/// Compression used for the SWFs that movies are compiled to.
+pub const SWF_COMPRESSION: Compression = Compression::Zlib;
 
 #[derive(Serialize, Deserialize)]
 pub struct Movie {
@@ -11,11 +15,31 @@ pub struct Movie {
     pub width: f64,
     pub height: f64,
     pub frame_rate: f32,
+    /// Optional title of the movie, shown in the player's window title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Scale mode requested by the movie, used unless the player is told to force its own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale_mode: Option<ScaleMode>,
     
     pub symbols: Vec<Symbol>,
     pub root: Vec<PlaceSymbol>,
 }
 
+/// How the stage is fit into the viewport, mirroring Flash's `StageScaleMode`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScaleMode {
+    /// Stretch the stage to fill the viewport, ignoring its aspect ratio.
+    ExactFit,
+    /// Scale the stage to cover the whole viewport, cropping it if needed.
+    NoBorder,
+    /// Don't scale the stage at all.
+    NoScale,
+    /// Scale the stage to fit inside the viewport, letterboxing it if needed.
+    #[default]
+    ShowAll,
+}
+
 #[derive(Serialize, Deserialize)]
 pub enum Symbol {
     Bitmap(Bitmap),
@@ -52,20 +76,36 @@ fn load_json(path:&Path) -> Result<Movie, Box<dyn std::error::Error>> {
     Ok(movie)
 }
 
-pub fn load_movie(path: PathBuf) -> Movie {
-    let directory = path.parent().unwrap();
-    let file = std::fs::File::open(path.clone()).expect("Unable to load file");
-    let mut movie: Movie = serde_json::from_reader(file).expect("Unable to load file");
+pub fn load_movie(path: PathBuf) -> anyhow::Result<Movie> {
+    let directory = path.parent().unwrap_or(Path::new(""));
+    let file = std::fs::File::open(&path)
+        .with_context(|| format!("Unable to open {}", path.display()))?;
+    let movie: Movie = serde_json::from_reader(file)
+        .with_context(|| format!("Unable to parse {}", path.display()))?;
+    load_bitmaps(movie, directory)
+}
 
+/// Loads a movie that's already in memory, such as one piped in through standard input.
+/// Images are looked for relative to the working directory, as there's no project directory to go by.
+pub fn load_movie_from_bytes(data: &[u8]) -> anyhow::Result<Movie> {
+    let movie: Movie = serde_json::from_slice(data).context("Unable to parse movie")?;
+    load_bitmaps(movie, Path::new(""))
+}
+
+fn load_bitmaps(mut movie: Movie, directory: &Path) -> anyhow::Result<Movie> {
     for symbol in movie.symbols.iter_mut() {
         let Symbol::Bitmap(bitmap) = symbol else {
             continue;
         };
-        let path = &bitmap.path;
-        bitmap.image = Some(ImageReader::open(directory.join(path)).expect("Unable to read image").decode().expect("Unable to decode image"));
+        let image_path = directory.join(&bitmap.path);
+        let image = ImageReader::open(&image_path)
+            .with_context(|| format!("Unable to read image {}", image_path.display()))?
+            .decode()
+            .with_context(|| format!("Unable to decode image {}", image_path.display()))?;
+        bitmap.image = Some(image);
     }
     
-    movie
+    Ok(movie)
 }
 
 fn main() {
@@ -79,7 +119,7 @@ fn main() {
 
 fn movie_to_swf<'a>(movie: Movie, project_directory: &Path, swf_path: PathBuf) {
     let header = Header {
-        compression: Compression::Zlib,
+        compression: SWF_COMPRESSION,
         version: movie.version,
         stage_size: Rectangle {
             x_min: Twips::from_pixels(0.0),
@@ -1,39 +1,406 @@
 use std::path::PathBuf;
+use std::time::Duration;
 
-use ruffle_render::{backend::RenderBackend, commands::{CommandList, Command}, matrix::Matrix, bitmap::{Bitmap, BitmapFormat, BitmapHandle, PixelSnapping}, transform::Transform};
-use swf::{Color, Twips, ColorTransform};
+use ruffle_render::{backend::RenderBackend, quality::StageQuality, commands::{CommandList, Command}, matrix::Matrix, bitmap::{Bitmap, BitmapFormat, BitmapHandle, PixelSnapping}, transform::Transform};
+use swf::{Color, Compression, Twips, ColorTransform};
 use tracing::instrument;
 use crate::editor::main::Movie;
 
-use super::main::{Symbol, PlaceSymbol};
+use super::main::{Symbol, PlaceSymbol, ScaleMode, SWF_COMPRESSION};
 
 
 type Renderer = Box<dyn RenderBackend>;
 
+/// Color of the stage behind everything placed on it.
+const STAGE_COLOR: Color = Color { r: 255, g: 255, b: 255, a: 255 };
+
+/// How many pixels a zoomed-in stage moves for each line scrolled with the mouse wheel.
+const PIXELS_PER_WHEEL_LINE: f64 = 40.0;
+
+/// Information about a loaded movie, mirroring what a SWF header would contain.
+#[derive(Clone, Debug)]
+pub struct MovieMetadata {
+    pub width: f64,
+    pub height: f64,
+    pub frame_rate: f32,
+    pub num_frames: u16,
+    pub version: u8,
+    /// The compression the movie gets when it's compiled to a SWF.
+    pub compression: Compression,
+    pub background_color: Color,
+    /// Size of the file the movie was read from, in bytes, which the player itself doesn't know.
+    pub file_size: Option<u64>,
+}
+
+/// An item in the right-click context menu, mirroring `ruffle_core::ContextMenuItem`.
+#[derive(Clone, Debug)]
+pub struct ContextMenuItem {
+    pub enabled: bool,
+    pub separator_before: bool,
+    pub checked: bool,
+    pub caption: String,
+}
+
+/// What the items of the context menu do, in the order that `Player::prepare_context_menu` returns them.
+const CONTEXT_MENU_ACTIONS: [ContextMenuAction; 4] = [
+    ContextMenuAction::Play,
+    ContextMenuAction::Rewind,
+    ContextMenuAction::Forward,
+    ContextMenuAction::Back,
+];
+
+/// The playback controls that Flash Player offers in its context menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ContextMenuAction {
+    /// Plays or pauses the movie, and is checked while it plays.
+    Play,
+    /// Goes back to the first frame, and stops there.
+    Rewind,
+    /// Goes to the next frame, and stops there.
+    Forward,
+    /// Goes to the previous frame, and stops there.
+    Back,
+}
+
+/// Where the view is along one axis of a stage that's zoomed in past the viewport, for a scrollbar to show.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scrollbar {
+    /// How far along the stage the view is, from 0 at its start to 1 at its end.
+    pub position: f64,
+    /// How much of the stage's length is in view, from 0 to 1.
+    pub visible: f64,
+}
+
+/// Whether the area around the stage is covered up, mirroring `ruffle_core::config::Letterbox`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Letterbox {
+    Off,
+    /// Only letterbox while the window is fullscreen.
+    #[default]
+    Fullscreen,
+    On,
+}
 
 pub struct Player {
     movie: Movie,
     renderer: Renderer,
+    is_playing: bool,
+    current_frame: u16,
+    /// How long the current frame has been shown for while playing.
+    frame_time: Duration,
+    volume: f32,
+    scale_mode: ScaleMode,
+    force_scale_mode: bool,
+    zoom: f32,
+    /// How far the stage is moved from the center of the viewport, in pixels.
+    /// It can only move while it's zoomed in past the viewport, and never so far that its edges come into view.
+    scroll: (f64, f64),
+    letterbox: Letterbox,
+    letterbox_color: Color,
+    is_fullscreen: bool,
+    /// Parameters ("flashvars") given to the movie, from the query string of its URL and from the user.
+    parameters: Vec<(String, String)>,
 }
 
 impl Player {
-    pub fn new(renderer: Renderer, path: PathBuf) -> Player {
+    pub fn new(renderer: Renderer, path: PathBuf) -> anyhow::Result<Player> {
         /*let directory = path.parent().unwrap();
         let file = std::fs::File::open(path.clone()).expect("Unable to load file");
         let movie: Movie = serde_json::from_reader(file).expect("Unable to load file");*/
-        let movie = crate::editor::main::load_movie(path);
+        let movie = crate::editor::main::load_movie(path)?;
+        Ok(Self::with_movie(renderer, movie))
+    }
+
+    /// Plays a movie that's already in memory, rather than in a file.
+    pub fn from_bytes(renderer: Renderer, data: &[u8]) -> anyhow::Result<Player> {
+        let movie = crate::editor::main::load_movie_from_bytes(data)?;
+        Ok(Self::with_movie(renderer, movie))
+    }
+
+    fn with_movie(renderer: Renderer, movie: Movie) -> Player {
         Player {
             movie,
-            renderer
+            renderer,
+            is_playing: true,
+            current_frame: 0,
+            frame_time: Duration::ZERO,
+            volume: 1.0,
+            scale_mode: ScaleMode::default(),
+            force_scale_mode: false,
+            zoom: 1.0,
+            scroll: (0.0, 0.0),
+            letterbox: Letterbox::default(),
+            letterbox_color: Color { r: 0, g: 0, b: 0, a: 255 },
+            is_fullscreen: false,
+            parameters: vec![],
+        }
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.movie.title.as_deref()
+    }
+
+    pub fn metadata(&self) -> MovieMetadata {
+        MovieMetadata {
+            width: self.movie.width,
+            height: self.movie.height,
+            frame_rate: self.movie.frame_rate,
+            num_frames: self.num_frames(),
+            version: self.movie.version,
+            compression: SWF_COMPRESSION,
+            background_color: STAGE_COLOR,
+            file_size: None,
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.is_playing
+    }
+
+    pub fn play(&mut self) {
+        self.is_playing = true;
+    }
+
+    pub fn pause(&mut self) {
+        self.is_playing = false;
+    }
+
+    /// Plays the movie on by `dt`, advancing a frame once the current one has been shown for long enough.
+    /// Playback stops on the last frame, and `true` is returned when it does, so that the next movie can be played.
+    /// A movie with a single frame is a still picture rather than an animation, so it never ends.
+    pub fn tick(&mut self, dt: Duration) -> bool {
+        if !self.is_playing || self.num_frames() < 2 {
+            self.frame_time = Duration::ZERO;
+            return false;
+        }
+        let frame_duration = self.frame_duration();
+        // Time beyond a frame isn't caught up on, so that a stall doesn't rush through the frames after it.
+        self.frame_time = (self.frame_time + dt).min(frame_duration);
+        if self.frame_time < frame_duration {
+            return false;
+        }
+        self.frame_time = Duration::ZERO;
+        if self.current_frame + 1 >= self.num_frames() {
+            self.is_playing = false;
+            return true;
+        }
+        self.current_frame += 1;
+        false
+    }
+
+    /// How long until [`Player::tick`] advances to the next frame, or `None` if it won't.
+    pub fn time_til_next_frame(&self) -> Option<Duration> {
+        if !self.is_playing || self.num_frames() < 2 {
+            return None;
+        }
+        Some(self.frame_duration().saturating_sub(self.frame_time))
+    }
+
+    /// How long each frame is shown for. Flash doesn't go slower than 0.01 frames per second.
+    fn frame_duration(&self) -> Duration {
+        Duration::from_secs_f32(1.0 / self.movie.frame_rate.max(0.01))
+    }
+
+    /// Advances the timeline by one frame, looping back to the first frame at the end.
+    pub fn step_forward(&mut self) {
+        self.current_frame = (self.current_frame + 1) % self.num_frames();
+    }
+
+    /// Moves the timeline back by one frame, looping around to the last frame at the start.
+    pub fn step_backward(&mut self) {
+        self.current_frame = self
+            .current_frame
+            .checked_sub(1)
+            .unwrap_or_else(|| self.num_frames() - 1);
+    }
+
+    /// Jumps to the given zero-based frame, clamped to the frames the movie has.
+    pub fn seek(&mut self, frame: u16) {
+        self.current_frame = frame.min(self.num_frames() - 1);
+    }
+
+    /// Projects don't contain sounds yet, so this only records the volume they will be played at.
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume;
+    }
+
+    /// Sets the scale mode used when the movie doesn't request one, or when `set_force_scale_mode` is on.
+    pub fn set_scale_mode(&mut self, scale_mode: ScaleMode) {
+        self.scale_mode = scale_mode;
+    }
+
+    /// Makes the player's scale mode take priority over the one requested by the movie.
+    pub fn set_force_scale_mode(&mut self, force_scale_mode: bool) {
+        self.force_scale_mode = force_scale_mode;
+    }
+
+    /// Magnifies the stage around the center of the viewport, on top of the scale mode.
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom;
+    }
+
+    pub fn set_letterbox(&mut self, letterbox: Letterbox) {
+        self.letterbox = letterbox;
+    }
+
+    pub fn set_letterbox_color(&mut self, color: Color) {
+        self.letterbox_color = color;
+    }
+
+    /// Sets the movie's parameters. Projects have no scripts to read them yet, so they're only kept alongside the movie.
+    pub fn set_parameters(&mut self, parameters: Vec<(String, String)>) {
+        self.parameters = parameters;
+    }
+
+    pub fn parameters(&self) -> &[(String, String)] {
+        &self.parameters
+    }
+
+    /// Lets the player know whether it's shown fullscreen, which affects `Letterbox::Fullscreen`.
+    pub fn set_fullscreen(&mut self, is_fullscreen: bool) {
+        self.is_fullscreen = is_fullscreen;
+    }
+
+    fn effective_scale_mode(&self) -> ScaleMode {
+        match self.movie.scale_mode {
+            Some(scale_mode) if !self.force_scale_mode => scale_mode,
+            _ => self.scale_mode,
+        }
+    }
+
+    /// The size of the area that the stage is drawn in, in pixels.
+    pub fn viewport_size(&self) -> (u32, u32) {
+        let viewport = self.renderer.viewport_dimensions();
+        (viewport.width, viewport.height)
+    }
+
+    /// Moves the zoomed-in stage by the given number of pixels, as far as it can go.
+    pub fn scroll_by(&mut self, dx: f64, dy: f64) {
+        let (max_x, max_y) = self.max_scroll();
+        self.scroll = (
+            (self.scroll.0 + dx).clamp(-max_x, max_x),
+            (self.scroll.1 + dy).clamp(-max_y, max_y),
+        );
+    }
+
+    /// Moves the zoomed-in stage so that the view is the given fraction of the way along it on each axis,
+    /// from 0 at its start to 1 at its end, like [`Scrollbar::position`].
+    pub fn scroll_to(&mut self, x: f64, y: f64) {
+        let (max_x, max_y) = self.max_scroll();
+        self.scroll = (
+            max_x - x.clamp(0.0, 1.0) * 2.0 * max_x,
+            max_y - y.clamp(0.0, 1.0) * 2.0 * max_y,
+        );
+    }
+
+    /// The horizontal and vertical scrollbars, for each axis that the stage is zoomed in past the viewport on.
+    pub fn scrollbars(&self) -> (Option<Scrollbar>, Option<Scrollbar>) {
+        let Some((scale_x, scale_y)) = self.stage_scale() else {
+            return (None, None);
+        };
+        let (viewport_width, viewport_height) = self.viewport_size();
+        let (max_x, max_y) = self.max_scroll();
+        // The stage moves the opposite way to the view, so it's furthest along at the start.
+        let scrollbar = |stage_length: f64, viewport_length: u32, max: f64, scroll: f64| {
+            (max > 0.0).then(|| Scrollbar {
+                position: (max - scroll.clamp(-max, max)) / (2.0 * max),
+                visible: viewport_length as f64 / stage_length,
+            })
+        };
+        (
+            scrollbar(self.movie.width * scale_x, viewport_width, max_x, self.scroll.0),
+            scrollbar(self.movie.height * scale_y, viewport_height, max_y, self.scroll.1),
+        )
+    }
+
+    /// Whether the stage is zoomed in past the viewport, so that it can be moved around.
+    pub fn can_scroll(&self) -> bool {
+        self.max_scroll() != (0.0, 0.0)
+    }
+
+    /// How far the stage can move from the center of the viewport in each direction,
+    /// which is how far it overhangs each side of the viewport.
+    fn max_scroll(&self) -> (f64, f64) {
+        let Some((scale_x, scale_y)) = self.stage_scale() else {
+            return (0.0, 0.0);
+        };
+        let (viewport_width, viewport_height) = self.viewport_size();
+        (
+            ((self.movie.width * scale_x - viewport_width as f64) / 2.0).max(0.0),
+            ((self.movie.height * scale_y - viewport_height as f64) / 2.0).max(0.0),
+        )
+    }
+
+    /// Computes the matrix that places the stage within the viewport, according to the scale mode.
+    pub fn view_matrix(&self) -> Matrix {
+        let viewport = self.renderer.viewport_dimensions();
+        let (viewport_width, viewport_height) = (viewport.width as f64, viewport.height as f64);
+        let (stage_width, stage_height) = (self.movie.width, self.movie.height);
+        let Some((scale_x, scale_y)) = self.stage_scale() else {
+            return Matrix::IDENTITY;
+        };
+        // The viewport or zoom may have changed since the stage was scrolled, leaving less room to scroll in.
+        let (max_x, max_y) = self.max_scroll();
+        // Center the stage in whatever space is left over.
+        let x = (viewport_width - stage_width * scale_x) / 2.0 + self.scroll.0.clamp(-max_x, max_x);
+        let y = (viewport_height - stage_height * scale_y) / 2.0 + self.scroll.1.clamp(-max_y, max_y);
+        Matrix::translate(Twips::from_pixels(x), Twips::from_pixels(y))
+            * Matrix::scale(scale_x as f32, scale_y as f32)
+    }
+
+    /// How much the stage is scaled by in the viewport, according to the scale mode and zoom,
+    /// or `None` if the stage has no area to scale.
+    fn stage_scale(&self) -> Option<(f64, f64)> {
+        let viewport = self.renderer.viewport_dimensions();
+        let (viewport_width, viewport_height) = (viewport.width as f64, viewport.height as f64);
+        let (stage_width, stage_height) = (self.movie.width, self.movie.height);
+        if stage_width <= 0.0 || stage_height <= 0.0 {
+            return None;
         }
+        let width_ratio = viewport_width / stage_width;
+        let height_ratio = viewport_height / stage_height;
+        let (mut scale_x, mut scale_y) = match self.effective_scale_mode() {
+            ScaleMode::ExactFit => (width_ratio, height_ratio),
+            ScaleMode::NoBorder => {
+                let scale = width_ratio.max(height_ratio);
+                (scale, scale)
+            }
+            ScaleMode::NoScale => (1.0, 1.0),
+            ScaleMode::ShowAll => {
+                let scale = width_ratio.min(height_ratio);
+                (scale, scale)
+            }
+        };
+        scale_x *= self.zoom as f64;
+        scale_y *= self.zoom as f64;
+        Some((scale_x, scale_y))
+    }
+
+    pub fn set_quality(&mut self, quality: StageQuality) {
+        self.renderer.set_quality(quality);
+    }
+
+    /// The frame rate the movie is meant to be played at.
+    pub fn frame_rate(&self) -> f32 {
+        self.movie.frame_rate
+    }
+
+    pub fn current_frame(&self) -> u16 {
+        self.current_frame
+    }
+
+    /// Projects only have a single timeline frame for now, which is also what the SWF compiler emits.
+    pub fn num_frames(&self) -> u16 {
+        1
     }
     #[instrument(level = "debug", skip_all)]
     pub fn render(&mut self) {
+        let view_matrix = self.view_matrix();
         let mut commands = CommandList::new();
         // stage background
         commands.commands.push(Command::DrawRect {
-            color: Color::from_rgba(0xFFFFFFFF),
-            matrix: Matrix::create_box(
+            color: STAGE_COLOR,
+            matrix: view_matrix * Matrix::create_box(
                 self.movie.width as f32,
                 self.movie.height as f32,
                 0.0, 
@@ -68,23 +435,65 @@ impl Player {
         commands.commands.extend(Player::render_placed_symbols(
             renderer,
             symbols,
-            placed_symbols
+            placed_symbols,
+            view_matrix,
+            &mut vec![]
         ));
+        let is_letterboxed = match self.letterbox {
+            Letterbox::Off => false,
+            Letterbox::Fullscreen => self.is_fullscreen,
+            Letterbox::On => true,
+        };
+        if is_letterboxed {
+            commands.commands.extend(self.letterbox_commands(view_matrix));
+        }
         self.renderer.submit_frame(Color::from_rgb(0x222222, 255), commands, vec![]);
     }
     
-    fn render_placed_symbols(renderer: &mut Box<dyn RenderBackend>, symbols: &Vec<Symbol>, placed_symbols: &Vec<PlaceSymbol>) -> Vec<Command> {
+    /// Covers everything around the stage with the letterbox color.
+    fn letterbox_commands(&self, view_matrix: Matrix) -> Vec<Command> {
+        let viewport = self.renderer.viewport_dimensions();
+        let (viewport_width, viewport_height) = (viewport.width as f32, viewport.height as f32);
+        let left = view_matrix.tx.to_pixels() as f32;
+        let top = view_matrix.ty.to_pixels() as f32;
+        let right = left + self.movie.width as f32 * view_matrix.a;
+        let bottom = top + self.movie.height as f32 * view_matrix.d;
+        let bars = [
+            (0.0, 0.0, viewport_width, top),
+            (0.0, bottom, viewport_width, viewport_height - bottom),
+            (0.0, top, left, bottom - top),
+            (right, top, viewport_width - right, bottom - top),
+        ];
+        bars.into_iter()
+            .filter(|&(_, _, width, height)| width > 0.0 && height > 0.0)
+            .map(|(x, y, width, height)| Command::DrawRect {
+                color: self.letterbox_color,
+                matrix: Matrix::create_box(
+                    width,
+                    height,
+                    0.0,
+                    Twips::from_pixels(x as f64),
+                    Twips::from_pixels(y as f64),
+                ),
+            })
+            .collect()
+    }
+
+    /// `rendering` holds the movie clips that are being rendered further up, so that a clip containing itself
+    /// is only rendered once instead of forever. Placements of symbols that don't exist are skipped.
+    fn render_placed_symbols(renderer: &mut Box<dyn RenderBackend>, symbols: &Vec<Symbol>, placed_symbols: &Vec<PlaceSymbol>, view_matrix: Matrix, rendering: &mut Vec<u16>) -> Vec<Command> {
         let mut commands = vec![];
-        for i in 0..placed_symbols.len() {
-            let place_symbol = placed_symbols.get(i).unwrap();
-            let symbol = symbols.get(place_symbol.symbol_id as usize).expect("Invalid symbol placed");
+        for place_symbol in placed_symbols {
+            let Some(symbol) = symbols.get(place_symbol.symbol_id as usize) else {
+                continue;
+            };
             match symbol {
                 Symbol::Bitmap(bitmap) => {
                     let bitmap_handle = bitmap.bitmap_handle.as_ref().unwrap();
                     commands.push(Command::RenderBitmap {
                         bitmap: bitmap_handle.clone(),
                         transform: Transform {
-                            matrix: Matrix::translate(Twips::from_pixels(place_symbol.x), Twips::from_pixels(place_symbol.y)),
+                            matrix: view_matrix * Matrix::translate(Twips::from_pixels(place_symbol.x), Twips::from_pixels(place_symbol.y)),
                             color_transform: ColorTransform::IDENTITY
                         },
                         smoothing: false,
@@ -92,17 +501,146 @@ impl Player {
                     });
                 }
                 Symbol::MovieClip(movieclip) => {
+                    if rendering.contains(&place_symbol.symbol_id) {
+                        continue;
+                    }
+                    rendering.push(place_symbol.symbol_id);
                     commands.extend(Player::render_placed_symbols(
                         renderer,
                         symbols,
-                        &movieclip.place_symbols)
+                        &movieclip.place_symbols,
+                        view_matrix,
+                        rendering)
                     );
+                    rendering.pop();
                 }
             }
         }
         commands
     }
+
+    /// Describes the parts of the movie that the player can't show properly, which are skipped when rendering.
+    pub fn unsupported_content(&self) -> Vec<String> {
+        let symbols = &self.movie.symbols;
+        let mut warnings = vec![];
+        let missing = self
+            .movie
+            .root
+            .iter()
+            .chain(symbols.iter().flat_map(|symbol| match symbol {
+                Symbol::MovieClip(movieclip) => movieclip.place_symbols.as_slice(),
+                Symbol::Bitmap(_) => &[],
+            }))
+            .filter(|place_symbol| place_symbol.symbol_id as usize >= symbols.len())
+            .count();
+        if missing > 0 {
+            warnings.push(format!("{missing} placed symbol(s) refer to symbols that don't exist, and aren't shown"));
+        }
+        for (symbol_id, symbol) in symbols.iter().enumerate() {
+            if let Symbol::MovieClip(movieclip) = symbol {
+                if Player::contains_symbol(symbols, &movieclip.place_symbols, symbol_id as u16, &mut vec![]) {
+                    warnings.push(format!("Movie clip \"{}\" contains itself, and is only shown once", movieclip.name));
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Whether `symbol_id` is placed among `placed_symbols`, or anywhere inside the movie clips placed there.
+    fn contains_symbol(symbols: &[Symbol], placed_symbols: &[PlaceSymbol], symbol_id: u16, visited: &mut Vec<u16>) -> bool {
+        placed_symbols.iter().any(|place_symbol| {
+            if place_symbol.symbol_id == symbol_id {
+                return true;
+            }
+            if visited.contains(&place_symbol.symbol_id) {
+                return false;
+            }
+            visited.push(place_symbol.symbol_id);
+            match symbols.get(place_symbol.symbol_id as usize) {
+                Some(Symbol::MovieClip(movieclip)) => {
+                    Player::contains_symbol(symbols, &movieclip.place_symbols, symbol_id, visited)
+                }
+                _ => false,
+            }
+        })
+    }
     
+    /// Builds the items shown in the right-click context menu, which are Flash Player's playback controls.
+    /// Like there, going to a frame that doesn't exist isn't offered.
+    pub fn prepare_context_menu(&mut self) -> Vec<ContextMenuItem> {
+        let is_first_frame = self.current_frame == 0;
+        let is_last_frame = self.current_frame + 1 >= self.num_frames();
+        CONTEXT_MENU_ACTIONS
+            .into_iter()
+            .map(|action| {
+                let (caption, enabled) = match action {
+                    ContextMenuAction::Play => ("Play", true),
+                    ContextMenuAction::Rewind => ("Rewind", !is_first_frame),
+                    ContextMenuAction::Forward => ("Forward", !is_last_frame),
+                    ContextMenuAction::Back => ("Back", !is_first_frame),
+                };
+                ContextMenuItem {
+                    enabled,
+                    separator_before: action == ContextMenuAction::Rewind,
+                    checked: action == ContextMenuAction::Play && self.is_playing,
+                    caption: caption.to_string(),
+                }
+            })
+            .collect()
+    }
+
+    /// Runs the action of the context menu item at `index`, as returned by `prepare_context_menu`.
+    pub fn run_context_menu_callback(&mut self, index: usize) {
+        match CONTEXT_MENU_ACTIONS.get(index) {
+            Some(ContextMenuAction::Play) => self.is_playing = !self.is_playing,
+            Some(ContextMenuAction::Rewind) => {
+                self.pause();
+                self.seek(0);
+            }
+            Some(ContextMenuAction::Forward) => {
+                self.pause();
+                self.step_forward();
+            }
+            Some(ContextMenuAction::Back) => {
+                self.pause();
+                self.step_backward();
+            }
+            None => tracing::warn!("Unhandled context menu item {index}"),
+        }
+    }
+
+    /// Whether a text field has focus, for the Edit menu's clipboard actions to act on.
+    /// Projects don't contain editable text yet, so those actions stay unavailable.
+    pub fn has_text_focus(&self) -> bool {
+        false
+    }
+
+    /// Returns the selected text of the focused text field, removing it if `cut` is set.
+    pub fn copy_selection(&mut self, cut: bool) -> Option<String> {
+        tracing::debug!("No text is selected to {}", if cut { "cut" } else { "copy" });
+        None
+    }
+
+    /// Replaces the selection in the focused text field with `text`.
+    pub fn paste(&mut self, text: &str) {
+        tracing::debug!("No text field to paste {} characters into", text.chars().count());
+    }
+
+    /// Lets the movie know that the mouse wheel was scrolled, by a number of lines. Positive is up.
+    /// A zoomed-in stage is scrolled by it; otherwise the movie has no use for it, and `false` is returned.
+    pub fn mouse_wheel(&mut self, lines: f64) -> bool {
+        if !self.can_scroll() {
+            return false;
+        }
+        self.scroll_by(0.0, lines * PIXELS_PER_WHEEL_LINE);
+        true
+    }
+
+    /// Selects all the text in the focused text field.
+    pub fn select_all(&mut self) {
+        tracing::debug!("No text field to select");
+    }
+
     pub fn renderer_mut(&mut self) -> &mut Renderer {
         &mut self.renderer
     }
@@ -0,0 +1,159 @@
+//! Playing movies with a gamepad, by pressing keyboard keys on behalf of its buttons
+
+// The mapping is kept in the preferences even in builds without gamepad support, so that it isn't lost.
+#![cfg_attr(not(feature = "gamepad"), allow(dead_code))]
+
+use crate::shortcuts::BINDABLE_KEYS;
+use egui::Key;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// Gamepad buttons that can be mapped to a key, named after their position on the gamepad.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GamepadButton {
+    South,
+    East,
+    North,
+    West,
+    LeftTrigger,
+    LeftTrigger2,
+    RightTrigger,
+    RightTrigger2,
+    Select,
+    Start,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl GamepadButton {
+    pub const ALL: [Self; 14] = [
+        Self::South,
+        Self::East,
+        Self::North,
+        Self::West,
+        Self::LeftTrigger,
+        Self::LeftTrigger2,
+        Self::RightTrigger,
+        Self::RightTrigger2,
+        Self::Select,
+        Self::Start,
+        Self::DPadUp,
+        Self::DPadDown,
+        Self::DPadLeft,
+        Self::DPadRight,
+    ];
+
+    /// The name that the button is saved under.
+    fn name(self) -> &'static str {
+        match self {
+            Self::South => "south",
+            Self::East => "east",
+            Self::North => "north",
+            Self::West => "west",
+            Self::LeftTrigger => "left-trigger",
+            Self::LeftTrigger2 => "left-trigger-2",
+            Self::RightTrigger => "right-trigger",
+            Self::RightTrigger2 => "right-trigger-2",
+            Self::Select => "select",
+            Self::Start => "start",
+            Self::DPadUp => "dpad-up",
+            Self::DPadDown => "dpad-down",
+            Self::DPadLeft => "dpad-left",
+            Self::DPadRight => "dpad-right",
+        }
+    }
+
+    #[cfg(feature = "gamepad")]
+    pub fn from_gilrs(button: gilrs::Button) -> Option<Self> {
+        use gilrs::Button;
+        Some(match button {
+            Button::South => Self::South,
+            Button::East => Self::East,
+            Button::North => Self::North,
+            Button::West => Self::West,
+            Button::LeftTrigger => Self::LeftTrigger,
+            Button::LeftTrigger2 => Self::LeftTrigger2,
+            Button::RightTrigger => Self::RightTrigger,
+            Button::RightTrigger2 => Self::RightTrigger2,
+            Button::Select => Self::Select,
+            Button::Start => Self::Start,
+            Button::DPadUp => Self::DPadUp,
+            Button::DPadDown => Self::DPadDown,
+            Button::DPadLeft => Self::DPadLeft,
+            Button::DPadRight => Self::DPadRight,
+            _ => return None,
+        })
+    }
+}
+
+/// The key that each gamepad button presses. Buttons that aren't mapped don't do anything.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GamepadMapping {
+    keys: BTreeMap<GamepadButton, Key>,
+}
+
+/// Arrow keys on the D-pad, and the keys that games most often use for actions on the face buttons.
+impl Default for GamepadMapping {
+    fn default() -> Self {
+        Self {
+            keys: BTreeMap::from([
+                (GamepadButton::South, Key::Space),
+                (GamepadButton::East, Key::Enter),
+                (GamepadButton::North, Key::X),
+                (GamepadButton::West, Key::Z),
+                (GamepadButton::Start, Key::Enter),
+                (GamepadButton::DPadUp, Key::ArrowUp),
+                (GamepadButton::DPadDown, Key::ArrowDown),
+                (GamepadButton::DPadLeft, Key::ArrowLeft),
+                (GamepadButton::DPadRight, Key::ArrowRight),
+            ]),
+        }
+    }
+}
+
+impl GamepadMapping {
+    pub fn get(&self, button: GamepadButton) -> Option<Key> {
+        self.keys.get(&button).copied()
+    }
+
+    /// Maps the button to the given key, or unmaps it if that's `None`.
+    pub fn set(&mut self, button: GamepadButton, key: Option<Key>) {
+        match key {
+            Some(key) => self.keys.insert(button, key),
+            None => self.keys.remove(&button),
+        };
+    }
+}
+
+impl Serialize for GamepadMapping {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.keys
+                .iter()
+                .map(|(button, key)| (button.name(), key.name())),
+        )
+    }
+}
+
+/// Unknown buttons and keys are skipped, like unknown shortcuts are.
+impl<'de> Deserialize<'de> for GamepadMapping {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let saved = BTreeMap::<String, String>::deserialize(deserializer)?;
+        let mut mapping = Self {
+            keys: BTreeMap::new(),
+        };
+        for (name, key_name) in saved {
+            let button = GamepadButton::ALL
+                .into_iter()
+                .find(|button| button.name() == name);
+            let key = BINDABLE_KEYS.into_iter().find(|key| key.name() == key_name);
+            match (button, key) {
+                (Some(button), Some(key)) => mapping.set(button, Some(key)),
+                _ => tracing::warn!("Ignoring unknown gamepad mapping {name} = {key_name}"),
+            }
+        }
+        Ok(mapping)
+    }
+}
@@ -1,19 +1,43 @@
 mod controller;
 mod movie;
 
-pub use controller::GuiController;
+pub use controller::{GuiController, MovieSource};
 pub use movie::MovieView;
 use std::borrow::Cow;
+#[cfg(test)]
+use std::cell::Cell;
+use std::cell::RefCell;
 
-use crate::custom_event::RuffleEvent;
-use chrono::DateTime;
+use crate::console::{ConsoleLevel, ConsoleLine, LogBuffer, MAX_CONSOLE_LINES};
+use crate::custom_event::{ClipboardCommand, PlaybackCommand, RuffleEvent};
+use crate::editor::main::ScaleMode;
+use crate::editor::player::{ContextMenuItem, Letterbox, MovieMetadata, Player, Scrollbar};
+use crate::preferences::{
+    config_dir, AutoplayPolicy, Bookmark, MaxFps, Preferences, ReduceMotion, Theme,
+    UI_SCALE_RANGE,
+};
+#[cfg(feature = "gamepad")]
+use crate::gamepad::{GamepadButton, GamepadMapping};
+#[cfg(feature = "gamepad")]
+use crate::shortcuts::BINDABLE_KEYS;
+use crate::shortcuts::{ShortcutAction, Shortcuts};
+use crate::update::{self, Release};
+use crate::util::{
+    classify_url_input, clipboard_supports_images, is_supported_file, log_file, parse_url, UrlInputKind,
+};
+use chrono::{DateTime, Local};
 use egui::*;
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
 use fluent_templates::fluent_bundle::FluentValue;
 use fluent_templates::loader::langid;
 use fluent_templates::{static_loader, Loader};
-use std::collections::HashMap;
-use sys_locale::get_locale;
+use ruffle_render::quality::StageQuality;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use sys_locale::get_locales;
 use unic_langid::LanguageIdentifier;
+use url::Url;
 use winit::event_loop::EventLoopProxy;
 
 static US_ENGLISH: LanguageIdentifier = langid!("en-US");
@@ -25,14 +49,43 @@ static_loader! {
     };
 }
 
-pub fn text<'a>(locale: &LanguageIdentifier, id: &'a str) -> Cow<'a, str> {
-    TEXTS.lookup(locale, id).map(Cow::Owned).unwrap_or_else(|| {
+thread_local! {
+    /// Texts that have already been looked up, by locale and then by id.
+    /// Menus ask for the same texts every frame, and resolving them through Fluent each time adds up.
+    /// The texts are leaked so that they can be handed out without copying.
+    /// There's at most one for each id in each language, so this doesn't grow for as long as the GUI runs.
+    static TEXT_CACHE: RefCell<HashMap<LanguageIdentifier, HashMap<&'static str, &'static str>>> =
+        RefCell::new(HashMap::new());
+}
+
+#[cfg(test)]
+thread_local! {
+    /// How many texts have been resolved through Fluent, for tests to check that the cache is used.
+    static TEXT_LOOKUPS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Looks up the text with the given id, returning `None` if there's no translation for it.
+pub fn try_text(locale: &LanguageIdentifier, id: &'static str) -> Option<Cow<'static, str>> {
+    TEXT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some(&text) = cache.get(locale).and_then(|texts| texts.get(id)) {
+            return Some(Cow::Borrowed(text));
+        }
+        #[cfg(test)]
+        TEXT_LOOKUPS.with(|lookups| lookups.set(lookups.get() + 1));
+        let text: &'static str = Box::leak(TEXTS.lookup(locale, id)?.into_boxed_str());
+        cache.entry(locale.clone()).or_default().insert(id, text);
+        Some(Cow::Borrowed(text))
+    })
+}
+
+pub fn text(locale: &LanguageIdentifier, id: &'static str) -> Cow<'static, str> {
+    try_text(locale, id).unwrap_or_else(|| {
         tracing::error!("Unknown desktop text id '{id}'");
         Cow::Borrowed(id)
     })
 }
 
-#[allow(dead_code)]
 pub fn text_with_args<'a, T: AsRef<str>>(
     locale: &LanguageIdentifier,
     id: &'a str,
@@ -47,269 +100,4028 @@ pub fn text_with_args<'a, T: AsRef<str>>(
         })
 }
 
-/// Size of the top menu bar in pixels.
-/// This is the offset at which the movie will be shown,
-/// and added to the window size if trying to match a movie.
+/// Widget id of the text box in the "Open URL" prompt, used to focus it when the prompt opens.
+const OPEN_URL_TEXT_ID: &str = "open_url_text";
+
+/// Returns every language that has translations available, sorted by identifier.
+pub fn available_languages() -> Vec<&'static LanguageIdentifier> {
+    let mut languages: Vec<_> = TEXTS.locales().collect();
+    languages.sort();
+    languages
+}
+
+/// The name of a language in that language itself, or its identifier if its translation doesn't give one.
+pub fn language_name(language: &LanguageIdentifier) -> String {
+    // Looked up directly rather than through the cache, which only holds texts for the GUI's own locale.
+    let name = TEXTS.lookup(language, "language-name");
+    // Translations without a name of their own get the English one, which would be misleading.
+    let is_fallback = language != &US_ENGLISH && name == TEXTS.lookup(&US_ENGLISH, "language-name");
+    match name {
+        Some(name) if !is_fallback => name,
+        _ => language.to_string(),
+    }
+}
+
+/// Picks the best available language for the given list of requested languages, in order of preference.
+/// Close matches are accepted, so `de-AT` resolves to `de` if there's no dedicated `de-AT` translation.
+pub fn negotiate_locale(requested: &[LanguageIdentifier]) -> LanguageIdentifier {
+    negotiate_locale_among(requested, &available_languages())
+}
+
+/// Picks the best of the `available` languages for the requested ones, or US English if none of them fit.
+fn negotiate_locale_among(
+    requested: &[LanguageIdentifier],
+    available: &[&LanguageIdentifier],
+) -> LanguageIdentifier {
+    negotiate_languages(requested, available, None, NegotiationStrategy::Lookup)
+        .first()
+        .map(|language| (**language).clone())
+        .unwrap_or_else(|| US_ENGLISH.clone())
+}
+
+/// Picks the language the GUI is shown in: the one chosen by the user if any, then the system locales.
+/// A chosen language is negotiated too, as one edited into the preferences by hand may have no translation of its
+/// own, and the system locales are what's tried next if it has none at all.
+fn resolve_locale(language_override: Option<&LanguageIdentifier>) -> LanguageIdentifier {
+    // TODO: This should also be somewhere else so it can be supplied through UiBackend too
+    let requested: Vec<LanguageIdentifier> = language_override
+        .cloned()
+        .into_iter()
+        .chain(get_locales().filter_map(|locale| locale.parse().ok()))
+        .collect();
+    negotiate_locale(&requested)
+}
+
+/// Formats an RFC 3339 timestamp for display in the local time zone,
+/// or returns it unchanged if it can't be parsed.
+pub fn format_timestamp(raw: &str) -> String {
+    DateTime::parse_from_rfc3339(raw)
+        .map(|t| t.with_timezone(&Local).format("%c").to_string())
+        .unwrap_or_else(|_| raw.to_string())
+}
+
+/// Builds a plain-text summary of this build, for pasting into bug reports.
+/// Timestamps are left as they are, as local time would be ambiguous to whoever reads the report.
+fn version_info(locale: &LanguageIdentifier) -> String {
+    [
+        ("about-ruffle-version", env!("CARGO_PKG_VERSION").to_string()),
+        ("about-ruffle-channel", env!("CFG_RELEASE_CHANNEL").to_string()),
+        ("about-ruffle-build-time", env!("VERGEN_BUILD_TIMESTAMP").to_string()),
+        ("about-ruffle-commit-ref", env!("VERGEN_GIT_SHA").to_string()),
+        (
+            "about-ruffle-commit-time",
+            env!("VERGEN_GIT_COMMIT_TIMESTAMP").to_string(),
+        ),
+        (
+            "about-ruffle-build-features",
+            env!("VERGEN_CARGO_FEATURES").replace(',', ", "),
+        ),
+    ]
+    .iter()
+    .map(|(id, value)| format!("{}: {value}", text(locale, *id)))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// How long the "Copied!" confirmation stays visible after copying something.
+const COPIED_CONFIRMATION_DURATION: Duration = Duration::from_secs(2);
+
+/// How long a toast stays on screen, including its fade out.
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+/// How long a toast takes to fade out at the end of its life.
+const TOAST_FADE_DURATION: Duration = Duration::from_millis(500);
+
+/// How a toast is styled, following how serious its message is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    /// Info for the outcome of something that worked, and error for something that didn't.
+    fn of<T>(result: &anyhow::Result<T>) -> Self {
+        if result.is_ok() {
+            Self::Info
+        } else {
+            Self::Error
+        }
+    }
+}
+
+/// How close to the top of the screen, in points, the pointer has to be to reveal the menu bar in fullscreen.
+const MENU_REVEAL_DISTANCE: f32 = 4.0;
+
+/// How long the menu bar stays revealed in fullscreen after the pointer leaves it.
+const MENU_HIDE_DELAY: Duration = Duration::from_secs(1);
+
+/// Number of recent frames that the FPS counter averages over.
+const FPS_SAMPLE_COUNT: usize = 60;
+
+/// The levels of console lines that can be shown or hidden, with the ids of their names.
+const CONSOLE_LEVELS: [(ConsoleLevel, &str); 4] = [
+    (ConsoleLevel::Error, "console-level-error"),
+    (ConsoleLevel::Warn, "console-level-warn"),
+    (ConsoleLevel::Info, "console-level-info"),
+    (ConsoleLevel::Debug, "console-level-debug"),
+];
+
+/// The speaker shown at the end of the menu bar, with as many waves as the volume is loud.
+fn volume_icon(volume: f32, is_muted: bool) -> &'static str {
+    if is_muted || volume == 0.0 {
+        "🔇"
+    } else if volume < 1.0 / 3.0 {
+        "🔈"
+    } else if volume < 2.0 / 3.0 {
+        "🔉"
+    } else {
+        "🔊"
+    }
+}
+
+/// The text shown next to the Preferences menu item for its shortcut.
+/// egui has no key for `,`, so the shortcut itself is handled by the app instead of the menu.
+fn preferences_shortcut_text(egui_ctx: &egui::Context) -> String {
+    let is_mac = matches!(egui_ctx.os(), egui::os::OperatingSystem::Mac);
+    format!("{}+,", ModifierNames::NAMES.format(&Modifiers::COMMAND, is_mac))
+}
+
+/// Dark visuals with pure black backgrounds, white text and white outlines around every widget.
+fn high_contrast_visuals() -> Visuals {
+    let mut visuals = Visuals::dark();
+    visuals.override_text_color = Some(Color32::WHITE);
+    visuals.hyperlink_color = Color32::YELLOW;
+    visuals.panel_fill = Color32::BLACK;
+    visuals.window_fill = Color32::BLACK;
+    visuals.extreme_bg_color = Color32::BLACK;
+    visuals.faint_bg_color = Color32::from_gray(24);
+    visuals.window_stroke = Stroke::new(1.0, Color32::WHITE);
+    visuals.selection.bg_fill = Color32::from_rgb(0, 0, 160);
+    visuals.selection.stroke = Stroke::new(1.0, Color32::WHITE);
+    let widgets = &mut visuals.widgets;
+    for widget in [&mut widgets.noninteractive, &mut widgets.inactive, &mut widgets.open] {
+        widget.bg_fill = Color32::BLACK;
+        widget.weak_bg_fill = Color32::BLACK;
+        widget.bg_stroke = Stroke::new(1.0, Color32::WHITE);
+    }
+    for widget in [&mut widgets.hovered, &mut widgets.active] {
+        widget.bg_stroke = Stroke::new(2.0, Color32::YELLOW);
+    }
+    visuals
+}
+
+/// Qualities that can be picked in the GUI, with the ids of their names.
+const QUALITY_OPTIONS: [(StageQuality, &str); 4] = [
+    (StageQuality::Low, "view-menu-quality-low"),
+    (StageQuality::Medium, "view-menu-quality-medium"),
+    (StageQuality::High, "view-menu-quality-high"),
+    (StageQuality::Best, "view-menu-quality-best"),
+];
+
+/// Most frames that can be recorded in one go.
+const MAX_RECORDED_FRAMES: u32 = 10_000;
+
+/// Radius of the play button drawn over movies that are waiting to be clicked, in points.
+const PLAY_BUTTON_RADIUS: f32 = 40.0;
+
+/// Thickness of the scrollbars drawn over a zoomed-in movie, in points.
+const SCROLLBAR_WIDTH: f32 = 10.0;
+
+/// Where a check for a newer release is at.
+enum UpdateCheck {
+    Checking,
+    Finished(anyhow::Result<Option<Release>>),
+}
+
+/// Themes that can be picked in the GUI, with the ids of their names.
+const THEME_OPTIONS: [(Theme, &str); 4] = [
+    (Theme::System, "settings-menu-theme-system"),
+    (Theme::Light, "settings-menu-theme-light"),
+    (Theme::Dark, "settings-menu-theme-dark"),
+    (Theme::HighContrast, "settings-menu-theme-high-contrast"),
+];
+
+/// Gamepad buttons that can be mapped to keys in the preferences, with the ids of their names.
+#[cfg(feature = "gamepad")]
+const GAMEPAD_BUTTONS: [(GamepadButton, &str); 14] = [
+    (GamepadButton::South, "preferences-gamepad-south"),
+    (GamepadButton::East, "preferences-gamepad-east"),
+    (GamepadButton::North, "preferences-gamepad-north"),
+    (GamepadButton::West, "preferences-gamepad-west"),
+    (GamepadButton::LeftTrigger, "preferences-gamepad-left-trigger"),
+    (GamepadButton::LeftTrigger2, "preferences-gamepad-left-trigger-2"),
+    (GamepadButton::RightTrigger, "preferences-gamepad-right-trigger"),
+    (GamepadButton::RightTrigger2, "preferences-gamepad-right-trigger-2"),
+    (GamepadButton::Select, "preferences-gamepad-select"),
+    (GamepadButton::Start, "preferences-gamepad-start"),
+    (GamepadButton::DPadUp, "preferences-gamepad-dpad-up"),
+    (GamepadButton::DPadDown, "preferences-gamepad-dpad-down"),
+    (GamepadButton::DPadLeft, "preferences-gamepad-dpad-left"),
+    (GamepadButton::DPadRight, "preferences-gamepad-dpad-right"),
+];
+
+/// Actions whose shortcut can be rebound in the preferences, with the ids of their names.
+const SHORTCUT_ACTIONS: [(ShortcutAction, &str); 14] = [
+    (ShortcutAction::OpenFile, "preferences-shortcut-open-file"),
+    (ShortcutAction::Reload, "preferences-shortcut-reload"),
+    (ShortcutAction::TakeScreenshot, "preferences-shortcut-take-screenshot"),
+    (ShortcutAction::CopyFrame, "preferences-shortcut-copy-frame"),
+    (ShortcutAction::Quit, "preferences-shortcut-quit"),
+    (ShortcutAction::PlayPause, "preferences-shortcut-play-pause"),
+    (ShortcutAction::Fullscreen, "preferences-shortcut-fullscreen"),
+    (ShortcutAction::ZoomIn, "preferences-shortcut-zoom-in"),
+    (ShortcutAction::ZoomOut, "preferences-shortcut-zoom-out"),
+    (ShortcutAction::ZoomReset, "preferences-shortcut-zoom-reset"),
+    (ShortcutAction::IncreaseUiScale, "preferences-shortcut-increase-ui-scale"),
+    (ShortcutAction::DecreaseUiScale, "preferences-shortcut-decrease-ui-scale"),
+    (ShortcutAction::Kiosk, "preferences-shortcut-kiosk"),
+    (ShortcutAction::HideMenuBar, "preferences-shortcut-hide-menu-bar"),
+];
+
+/// Maps positions in the window, in points, to coordinates on the stage. The stage is only ever moved and scaled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StageTransform {
+    /// Where the top left corner of the stage is in the window.
+    pub origin: Pos2,
+    /// How many stage pixels there are to a point, horizontally and vertically.
+    pub scale: Vec2,
+}
+
+impl StageTransform {
+    fn to_stage(self, position: Pos2) -> Pos2 {
+        pos2(
+            (position.x - self.origin.x) * self.scale.x,
+            (position.y - self.origin.y) * self.scale.y,
+        )
+    }
+}
+
+/// The file name that a movie's URL ends with, or the whole URL if it doesn't have one.
+fn url_name(url: &Url) -> &str {
+    url.path_segments()
+        .and_then(|segments| segments.last())
+        .filter(|segment| !segment.is_empty())
+        .unwrap_or_else(|| url.as_str())
+}
+
+/// What the error dialog shows.
+struct ErrorMessage {
+    message: String,
+    /// The underlying error, shown below the message for anyone who wants to look into it.
+    detail: Option<String>,
+}
+
+/// Why the input to the Open URL prompt couldn't be opened.
+struct OpenUrlError {
+    message: String,
+    /// A corrected URL that can be opened instead with one click, for mistakes that are easy to fix.
+    fix: Option<Url>,
+}
+
+/// Works out how to open what was typed into the Open URL prompt,
+/// or explains what's wrong with it, with a fix if it looks like a common mistake.
+fn open_url_event(
+    locale: &LanguageIdentifier,
+    input: &str,
+    parameters: Vec<(String, String)>,
+) -> Result<RuffleEvent, OpenUrlError> {
+    let error = |id, args: HashMap<&str, FluentValue>, fix| OpenUrlError {
+        message: text_with_args(locale, id, &args).into_owned(),
+        fix,
+    };
+    match classify_url_input(input) {
+        UrlInputKind::Url(url) => Ok(RuffleEvent::OpenURL(url, parameters)),
+        // Local paths are accepted too, for convenience.
+        UrlInputKind::LocalPath(path) if path.exists() => Ok(if parameters.is_empty() {
+            RuffleEvent::OpenFile(path)
+        } else {
+            // Only URLs carry parameters, so the path is opened as one.
+            match parse_url(&path) {
+                Ok(url) => RuffleEvent::OpenURL(url, parameters),
+                Err(_) => RuffleEvent::OpenFile(path),
+            }
+        }),
+        UrlInputKind::LocalPath(path) => {
+            let args = HashMap::from([("path", FluentValue::from(path.display().to_string()))]);
+            Err(error("open-url-missing-file", args, None))
+        }
+        UrlInputKind::MissingScheme(url) => {
+            let args = HashMap::from([("url", FluentValue::from(url.to_string()))]);
+            Err(error("open-url-missing-scheme", args, Some(url)))
+        }
+        UrlInputKind::UnsupportedScheme(scheme) => {
+            let args = HashMap::from([("scheme", FluentValue::from(scheme))]);
+            Err(error("open-url-unsupported-scheme", args, None))
+        }
+        UrlInputKind::Invalid(e) => {
+            tracing::error!("Invalid URL {input}: {e}");
+            let args = HashMap::from([("error", FluentValue::from(e))]);
+            Err(error("open-url-invalid", args, None))
+        }
+    }
+}
+
+/// A menu item that can also be triggered with a keyboard shortcut,
+/// so that the shortcut shown in the menu can't drift apart from the one that works.
+struct MenuItem {
+    /// The id of the item's label.
+    text_id: &'static str,
+    shortcut: Option<KeyboardShortcut>,
+    enabled: bool,
+    action: fn(&mut RuffleGui, &mut egui::Ui),
+}
+
+impl MenuItem {
+    fn new(text_id: &'static str, action: fn(&mut RuffleGui, &mut egui::Ui)) -> Self {
+        Self {
+            text_id,
+            shortcut: None,
+            enabled: true,
+            action,
+        }
+    }
+
+    fn shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+/// Choices for turning off animations in the GUI, with the ids of their names.
+const REDUCE_MOTION_OPTIONS: [(ReduceMotion, &str); 3] = [
+    (ReduceMotion::System, "preferences-reduce-motion-system"),
+    (ReduceMotion::On, "preferences-reduce-motion-on"),
+    (ReduceMotion::Off, "preferences-reduce-motion-off"),
+];
+
+/// Autoplay policies that can be picked in the GUI, with the ids of their names.
+const AUTOPLAY_OPTIONS: [(AutoplayPolicy, &str); 3] = [
+    (AutoplayPolicy::On, "preferences-autoplay-on"),
+    (AutoplayPolicy::Muted, "preferences-autoplay-muted"),
+    (AutoplayPolicy::Off, "preferences-autoplay-off"),
+];
+
+/// Frame rate limits offered in the preferences, with the ids of their names.
+const MAX_FPS_OPTIONS: [(MaxFps, &str); 4] = [
+    (MaxFps::Off, "preferences-max-fps-off"),
+    (MaxFps::Fps30, "preferences-max-fps-30"),
+    (MaxFps::Fps60, "preferences-max-fps-60"),
+    (MaxFps::MatchDisplay, "preferences-max-fps-match-display"),
+];
+
+/// Edit menu actions, with the key that triggers them together with the command key and the ids of their names.
+const CLIPBOARD_ITEMS: [(ClipboardCommand, Key, &str); 4] = [
+    (ClipboardCommand::Cut, Key::X, "edit-menu-cut"),
+    (ClipboardCommand::Copy, Key::C, "edit-menu-copy"),
+    (ClipboardCommand::Paste, Key::V, "edit-menu-paste"),
+    (ClipboardCommand::SelectAll, Key::A, "edit-menu-select-all"),
+];
+
+/// Hidden shortcut for translators to quickly compare languages.
+const CYCLE_LANGUAGE_SHORTCUT: KeyboardShortcut =
+    KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::L);
+
+/// Zoom levels offered in the "Zoom" submenu, and stepped through by the zoom shortcuts.
+const ZOOM_LEVELS: [f32; 10] = [0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 8.0];
+
+/// Range of zoom levels that can be picked as a custom zoom.
+const ZOOM_RANGE: std::ops::RangeInclusive<f32> = 0.1..=8.0;
+
+/// Sizes that the GUI can be scaled to in the preferences, and stepped through by the UI scale shortcuts.
+const UI_SCALES: [f32; 6] = [0.75, 1.0, 1.25, 1.5, 1.75, 2.0];
+
+/// Maximum number of entries in the "Open Recent" menu.
+const MAX_RECENT_FILES: usize = 10;
+
+/// Maximum number of addresses remembered by the Open URL prompt.
+const MAX_URL_HISTORY: usize = 20;
+
+/// How many warnings the warning banner lists before the rest are folded away.
+const MAX_SHOWN_WARNINGS: usize = 3;
+
+/// Where bugs get reported, with the bug report template already picked.
+const BUG_REPORT_URL: &str =
+    "https://github.com/ruffle-rs/ruffle/issues/new?assignees=&labels=bug&projects=&template=bug_report.yml";
+
+/// Size of the top menu bar in pixels, used until the menu bar has been laid out once.
+/// See [`RuffleGui::menu_height`] for the actual size.
 pub const MENU_HEIGHT: u32 = 24;
 
+/// Where the GUI sends its events to. That's the event loop, except in tests, which run without one.
+#[derive(Clone)]
+enum EventSender {
+    EventLoop(EventLoopProxy<RuffleEvent>),
+    #[cfg(test)]
+    Channel(std::sync::mpsc::Sender<RuffleEvent>),
+}
+
+impl EventSender {
+    /// Sends an event, handing it back if there's nothing left to receive it.
+    fn send_event(&self, event: RuffleEvent) -> Result<(), RuffleEvent> {
+        match self {
+            Self::EventLoop(event_loop) => event_loop.send_event(event).map_err(|e| e.0),
+            #[cfg(test)]
+            Self::Channel(sender) => sender.send(event).map_err(|e| e.0),
+        }
+    }
+}
+
 /// The main controller for the Ruffle GUI.
 pub struct RuffleGui {
-    event_loop: EventLoopProxy<RuffleEvent>,
+    event_loop: EventSender,
     open_url_text: String,
+    /// Parameters ("flashvars") entered in the Open URL prompt, as name and value.
+    open_url_parameters: Vec<(String, String)>,
+    open_url_error: Option<OpenUrlError>,
+    /// Addresses entered in the Open URL prompt, most recent first.
+    url_history: Vec<String>,
     is_about_visible: bool,
+    is_preferences_visible: bool,
+    is_movie_info_visible: bool,
+    is_exit_confirmation_visible: bool,
+    /// Metadata of the loaded movie, if there is one.
+    movie_metadata: Option<MovieMetadata>,
+    /// The error shown in the error dialog, if it's open.
+    error_message: Option<ErrorMessage>,
+    /// The website that couldn't be opened in a browser, while the dialog offering to copy it is open.
+    unopened_url: Option<String>,
+    update_check: Option<UpdateCheck>,
+    /// Whether the update check was started on startup rather than by the user,
+    /// in which case it's only shown if a newer release was found.
+    is_update_check_automatic: bool,
+    check_for_updates: bool,
+    /// Warnings about the current movie that haven't been dismissed, oldest first.
+    warnings: Vec<String>,
+    /// Whether the warning banner lists every warning, rather than only the first few.
+    are_warnings_expanded: bool,
+    /// URLs of movies that the user doesn't want to be warned about anymore.
+    silenced_warnings: Vec<String>,
+    /// When the version info was last copied from the About window, to show a confirmation.
+    version_info_copied_at: Option<Instant>,
     is_open_url_prompt_visible: bool,
-    //context_menu: Vec<ruffle_core::ContextMenuItem>,
+    is_console_visible: bool,
+    show_fps: bool,
+    /// Whether the loaded movie is reloaded whenever its file changes.
+    watch_file: bool,
+    /// Whether the stage coordinates under the pointer are shown next to it.
+    show_stage_coordinates: bool,
+    /// How the window maps to the stage of the loaded movie, if there is one.
+    stage_transform: Option<StageTransform>,
+    /// The horizontal and vertical scrollbars of the loaded movie, for each axis it's zoomed in past the window on.
+    scrollbars: (Option<Scrollbar>, Option<Scrollbar>),
+    show_status_bar: bool,
+    /// How long each of the most recent frames took, oldest first.
+    frame_times: VecDeque<Duration>,
+    /// Where log messages come in from, to be moved into the console.
+    log_buffer: LogBuffer,
+    /// Log messages shown in the console, oldest first.
+    console_lines: VecDeque<ConsoleLine>,
+    /// The levels of lines that the console shows.
+    console_levels: Vec<ConsoleLevel>,
+    /// Text that lines have to contain to be shown in the console, ignoring case.
+    console_search: String,
+    /// Whether long console lines wrap, rather than scrolling sideways.
+    console_wrap: bool,
+    /// Height of the console in physical pixels, or `None` if it wasn't shown last frame.
+    console_height: Option<u32>,
+    volume: f32,
+    is_muted: bool,
+    quality: StageQuality,
+    /// The volume and quality in the Preferences, which movies start with unless they've got their own.
+    default_volume: f32,
+    default_quality: StageQuality,
+    scale_mode: ScaleMode,
+    force_scale_mode: bool,
+    zoom: f32,
+    letterbox: Letterbox,
+    letterbox_color: Color32,
+    /// Files listed in the "Open Recent" menu, most recent first.
+    recent_files: Vec<PathBuf>,
+    bookmarks: Vec<Bookmark>,
+    /// Where the current movie was loaded from, so that it can be bookmarked.
+    movie_url: Option<Url>,
+    /// The name being entered for a new bookmark, while the dialog for it is open.
+    new_bookmark_name: Option<String>,
+    is_bookmarks_manager_visible: bool,
+    /// Movies queued to be played one after another.
+    playlist: Vec<Url>,
+    /// The entry of the playlist that's playing, if the current movie came from it.
+    playlist_position: Option<usize>,
+    is_playlist_visible: bool,
+    /// Whether the next entry of the playlist is played once the current one ends.
+    auto_advance: bool,
+    /// Whether the playlist starts over from its first entry after the last one.
+    loop_playlist: bool,
+    /// The file currently being dragged over the window, if any.
+    hovered_file: Option<PathBuf>,
+    context_menu: Vec<ContextMenuItem>,
+    /// The context menu always has Ruffle's own items, so it can be visible without any from the movie.
+    is_context_menu_visible: bool,
+    /// Where the context menu opens, which is where the pointer was when it was first shown.
+    context_menu_position: Option<Pos2>,
+    /// Height of the menu bar in physical pixels, as measured the last time it was shown.
+    menu_height: Option<u32>,
+    /// Height of the status bar in physical pixels, or `None` if it wasn't shown last frame.
+    status_bar_height: Option<u32>,
+    /// Whether the menu bar is hidden in fullscreen until the pointer moves to the top of the screen.
+    auto_hide_menu: bool,
+    /// Whether the menu bar is hidden in a window too, until the pointer moves to the top of it.
+    hide_menu_bar: bool,
+    start_fullscreen: bool,
+    always_on_top: bool,
+    /// When the pointer was last near the menu bar while it was auto-hidden.
+    menu_hovered_at: Option<Instant>,
+    is_window_focused: bool,
+    pause_on_unfocus: bool,
+    forward_mouse_wheel: bool,
+    confirm_exit: bool,
+    autoplay: AutoplayPolicy,
+    max_fps: MaxFps,
+    theme: Theme,
+    ui_scale: f32,
+    reduce_motion: ReduceMotion,
+    /// Whether the system asks for animations to be reduced, as read on startup.
+    system_reduces_motion: bool,
+    /// The system's color scheme, if the platform reports one.
+    system_theme: Option<winit::window::Theme>,
+    /// Whether changes to the preferences are saved, which they aren't if another window of the player
+    /// was opened first.
+    preferences_saved: bool,
+    /// The frame number being entered, while the Go to Frame dialog is open.
+    go_to_frame_text: Option<String>,
+    go_to_frame_error: Option<String>,
+    /// The number of frames to record, while the Record Frames dialog is open.
+    record_frames_count: Option<u32>,
+    /// The folder that recorded frames are saved to, kept for the next recording.
+    record_frames_dir: Option<PathBuf>,
+    /// How many frames have been saved out of how many, while frames are being recorded.
+    recording_progress: Option<(u32, u32)>,
+    /// Whether the menu bar and dialogs are hidden, so that the movie can't be navigated away from.
+    kiosk: bool,
+    /// Whether the platform's clipboard can take images, for "Copy Image".
+    can_copy_frame: bool,
+    /// Whether the movie is waiting for a click before it starts playing.
+    show_play_overlay: bool,
+    /// Whether the movie was paused because the window lost focus, rather than by the user.
+    is_auto_paused: bool,
+    /// Short-lived messages shown in the bottom corner, and when each was pushed.
+    toasts: Vec<(String, ToastLevel, Instant)>,
+    shortcuts: Shortcuts,
+    /// The action whose shortcut is being rebound, while waiting for the next key press.
+    rebinding_shortcut: Option<ShortcutAction>,
+    /// A shortcut that was just pressed for rebinding, and the id of the name of what it's already used for.
+    shortcut_conflict: Option<(KeyboardShortcut, &'static str)>,
+    #[cfg(feature = "gamepad")]
+    gamepad_mapping: GamepadMapping,
     locale: LanguageIdentifier,
+    /// The language picked by the user, or `None` to follow the system locale.
+    language_override: Option<LanguageIdentifier>,
 }
 
 impl RuffleGui {
-    fn new(event_loop: EventLoopProxy<RuffleEvent>) -> Self {
-        // TODO: language negotiation + https://github.com/1Password/sys-locale/issues/14
-        // This should also be somewhere else so it can be supplied through UiBackend too
-
-        let preferred_locale = get_locale();
-        let locale = preferred_locale
-            .and_then(|l| l.parse().ok())
-            .unwrap_or_else(|| US_ENGLISH.clone());
+    fn new(
+        event_loop: EventSender,
+        preferences: &Preferences,
+        log_buffer: LogBuffer,
+    ) -> Self {
+        let language_override: Option<LanguageIdentifier> = preferences
+            .language
+            .as_deref()
+            .and_then(|language| language.parse().ok());
+        let locale = resolve_locale(language_override.as_ref());
 
         Self {
             event_loop,
             open_url_text: String::new(),
+            open_url_parameters: vec![],
+            open_url_error: None,
+            url_history: preferences.url_history.clone(),
+            update_check: None,
+            is_update_check_automatic: false,
+            check_for_updates: preferences.check_for_updates,
+            warnings: vec![],
+            are_warnings_expanded: false,
+            silenced_warnings: preferences.silenced_warnings.clone(),
             is_about_visible: false,
+            is_preferences_visible: false,
+            is_movie_info_visible: false,
+            is_exit_confirmation_visible: false,
+            movie_metadata: None,
+            error_message: None,
+            unopened_url: None,
+            version_info_copied_at: None,
             is_open_url_prompt_visible: false,
-            //context_menu: vec![],
+            is_console_visible: false,
+            show_fps: false,
+            watch_file: false,
+            show_stage_coordinates: false,
+            stage_transform: None,
+            scrollbars: (None, None),
+            show_status_bar: false,
+            frame_times: VecDeque::with_capacity(FPS_SAMPLE_COUNT),
+            log_buffer,
+            console_lines: VecDeque::new(),
+            console_levels: vec![
+                ConsoleLevel::Error,
+                ConsoleLevel::Warn,
+                ConsoleLevel::Info,
+            ],
+            console_search: String::new(),
+            console_wrap: false,
+            console_height: None,
+            volume: 1.0,
+            is_muted: false,
+            quality: StageQuality::High,
+            default_volume: preferences.volume,
+            default_quality: preferences.quality,
+            scale_mode: ScaleMode::default(),
+            force_scale_mode: false,
+            zoom: 1.0,
+            letterbox: Letterbox::default(),
+            letterbox_color: Color32::BLACK,
+            recent_files: preferences.recent_files.clone(),
+            bookmarks: preferences.bookmarks.clone(),
+            movie_url: None,
+            new_bookmark_name: None,
+            is_bookmarks_manager_visible: false,
+            playlist: vec![],
+            playlist_position: None,
+            is_playlist_visible: false,
+            auto_advance: true,
+            loop_playlist: false,
+            hovered_file: None,
+            context_menu: vec![],
+            is_context_menu_visible: false,
+            context_menu_position: None,
+            menu_height: None,
+            status_bar_height: None,
+            auto_hide_menu: preferences.auto_hide_menu,
+            hide_menu_bar: preferences.hide_menu_bar,
+            start_fullscreen: preferences.start_fullscreen,
+            always_on_top: preferences.always_on_top,
+            menu_hovered_at: None,
+            is_window_focused: true,
+            pause_on_unfocus: preferences.pause_on_unfocus,
+            forward_mouse_wheel: preferences.forward_mouse_wheel,
+            confirm_exit: preferences.confirm_exit,
+            autoplay: preferences.autoplay,
+            max_fps: preferences.max_fps,
+            theme: preferences.theme,
+            ui_scale: preferences.ui_scale,
+            reduce_motion: preferences.reduce_motion,
+            // Read in the background, as that can take a while. Until then, animations play.
+            system_reduces_motion: false,
+            system_theme: None,
+            preferences_saved: true,
+            go_to_frame_text: None,
+            go_to_frame_error: None,
+            record_frames_count: None,
+            record_frames_dir: None,
+            recording_progress: None,
+            kiosk: false,
+            can_copy_frame: clipboard_supports_images(),
+            show_play_overlay: false,
+            is_auto_paused: false,
+            toasts: vec![],
+            shortcuts: preferences.shortcuts.clone(),
+            rebinding_shortcut: None,
+            shortcut_conflict: None,
+            #[cfg(feature = "gamepad")]
+            gamepad_mapping: preferences.gamepad_mapping.clone(),
             locale,
+            language_override,
         }
     }
 
     /// Renders all of the main Ruffle UI, including the main menu and context menus.
-    fn update(&mut self, egui_ctx: &egui::Context, show_menu: bool, has_movie: bool) {
-        if show_menu {
-            self.main_menu_bar(egui_ctx, has_movie);
+    fn update(
+        &mut self,
+        egui_ctx: &egui::Context,
+        show_menu: bool,
+        player: Option<&Player>,
+        is_fullscreen: bool,
+    ) {
+        let visuals = self.visuals();
+        if egui_ctx.style().visuals != visuals {
+            egui_ctx.set_visuals(visuals);
+        }
+        let animation_time = if self.is_motion_reduced() {
+            0.0
+        } else {
+            Style::default().animation_time
+        };
+        if egui_ctx.style().animation_time != animation_time {
+            let mut style = (*egui_ctx.style()).clone();
+            style.animation_time = animation_time;
+            egui_ctx.set_style(style);
         }
 
-        self.about_window(egui_ctx);
-        self.open_url_prompt(egui_ctx);
-
-        /*if !self.context_menu.is_empty() {
-            self.context_menu(egui_ctx);
-        }*/
-    }
-
-    /*pub fn show_context_menu(&mut self, menu: Vec<ruffle_core::ContextMenuItem>) {
-        self.context_menu = menu;
-    }*/
-
-    /*pub fn is_context_menu_visible(&self) -> bool {
-        !self.context_menu.is_empty()
-    }*/
+        // The next key press goes to the shortcut being rebound, instead of triggering anything.
+        if self.rebinding_shortcut.is_some() {
+            self.capture_shortcut(egui_ctx);
+        }
 
-    /// Renders the main menu bar at the top of the window.
-    fn main_menu_bar(&mut self, egui_ctx: &egui::Context, has_movie: bool) {
-        egui::TopBottomPanel::top("menu_bar").show(egui_ctx, |ui| {
-            // TODO(mike): Make some MenuItem struct with shortcut info to handle this more cleanly.
-            if ui.ctx().input_mut(|input| {
-                input.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::O))
-            }) {
-                self.open_file(ui);
-            }
-            if ui.ctx().input_mut(|input| {
-                input.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::Q))
-            }) {
-                self.request_exit(ui);
-            }
+        // Kiosk mode hides the menu bar, so this shortcut has to be handled outside of it.
+        let kiosk_shortcut = self.shortcuts.get(ShortcutAction::Kiosk);
+        if egui_ctx.input_mut(|input| input.consume_shortcut(&kiosk_shortcut)) {
+            self.dispatch(RuffleEvent::SetKiosk(!self.kiosk));
+        }
+        // Fullscreen hides the menu bar, so this shortcut has to be handled outside of it.
+        let fullscreen_shortcut = self.shortcuts.get(ShortcutAction::Fullscreen);
+        if !self.kiosk && egui_ctx.input_mut(|input| input.consume_shortcut(&fullscreen_shortcut)) {
+            self.toggle_fullscreen();
+        }
+        // The same goes for the shortcut that hides the menu bar itself.
+        let hide_menu_bar_shortcut = self.shortcuts.get(ShortcutAction::HideMenuBar);
+        if !self.kiosk
+            && egui_ctx.input_mut(|input| input.consume_shortcut(&hide_menu_bar_shortcut))
+        {
+            self.set_hide_menu_bar(!self.hide_menu_bar);
+        }
 
-            menu::bar(ui, |ui| {
-                menu::menu_button(ui, text(&self.locale, "file-menu"), |ui| {
-                    let mut shortcut;
-                    shortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::O);
+        self.auto_pause(player);
 
-                    if Button::new(text(&self.locale, "file-menu-open-file"))
-                        .shortcut_text(ui.ctx().format_shortcut(&shortcut))
-                        .ui(ui)
-                        .clicked()
-                    {
-                        self.open_file(ui);
-                    }
+        let show_menu = if self.kiosk {
+            false
+        } else if is_fullscreen {
+            !self.auto_hide_menu || self.is_menu_revealed(egui_ctx)
+        } else if self.hide_menu_bar {
+            self.is_menu_revealed(egui_ctx)
+        } else {
+            self.menu_hovered_at = None;
+            show_menu
+        };
+        if show_menu {
+            self.main_menu_bar(egui_ctx, player, is_fullscreen);
+        }
+        if self.show_status_bar && !is_fullscreen && !self.kiosk {
+            self.status_bar(egui_ctx, player);
+        } else {
+            self.status_bar_height = None;
+        }
+        for line in self.log_buffer.take() {
+            self.add_console_line(line);
+        }
+        if self.is_console_visible && !is_fullscreen && !self.kiosk {
+            self.console_panel(egui_ctx);
+        } else {
+            self.console_height = None;
+        }
 
-                    /*if Button::new(text(&self.locale, "file-menu-open-url")).ui(ui).clicked() {
-                        self.show_open_url_prompt(ui);
-                    }*/
+        if player.is_some() {
+            self.scrollbars_overlay(egui_ctx);
+        }
 
-                    if ui.add_enabled(has_movie, Button::new(text(&self.locale, "file-menu-close"))).clicked() {
-                        self.close_movie(ui);
-                    }
+        if !self.warnings.is_empty() && !self.kiosk {
+            self.warning_banner(egui_ctx, show_menu);
+        }
 
-                    ui.separator();
+        if self.show_play_overlay && player.is_some() {
+            self.play_overlay(egui_ctx, show_menu);
+        }
 
-                    shortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Q);
-                    if Button::new(text(&self.locale, "file-menu-exit"))
-                        .shortcut_text(ui.ctx().format_shortcut(&shortcut))
-                        .ui(ui)
-                        .clicked()
-                    {
-                        self.request_exit(ui);
-                    }
-                });
-                menu::menu_button(ui, text(&self.locale, "help-menu"), |ui| {
-                    if ui.button(text(&self.locale, "help-menu-join-discord")).clicked() {
-                        self.launch_website(ui, "https://discord.gg/ruffle");
-                    }
-                    if ui.button(text(&self.locale, "help-menu-report-a-bug")).clicked() {
-                        self.launch_website(ui, "https://github.com/ruffle-rs/ruffle/issues/new?assignees=&labels=bug&projects=&template=bug_report.yml");
-                    }
-                    if ui.button(text(&self.locale, "help-menu-sponsor-development")).clicked() {
-                        self.launch_website(ui, "https://opencollective.com/ruffle/");
-                    }
-                    if ui.button(text(&self.locale, "help-menu-translate-ruffle")).clicked() {
-                        self.launch_website(ui, "https://crowdin.com/project/ruffle");
-                    }
-                    ui.separator();
-                    if ui.button(text(&self.locale, "help-menu-about")).clicked() {
-                        self.show_about_screen(ui);
-                    }
-                })
-            });
-        });
-    }
+        if self.show_fps {
+            self.fps_overlay(egui_ctx, player, show_menu);
+        }
 
-    fn about_window(&mut self, egui_ctx: &egui::Context) {
-        egui::Window::new(text(&self.locale, "about-ruffle"))
-            .collapsible(false)
-            .resizable(false)
-            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
-            .open(&mut self.is_about_visible)
-            .show(egui_ctx, |ui| {
-                ui.vertical_centered(|ui| {
-                    ui.label(
-                        RichText::new("Ruffle")
-                            .color(Color32::from_rgb(0xFF, 0xAD, 0x33))
-                            .size(32.0),
-                    );
-                    Grid::new("about_ruffle_version_info")
-                        .striped(true)
-                        .show(ui, |ui| {
-                            ui.label(text(&self.locale, "about-ruffle-version"));
-                            ui.label(env!("CARGO_PKG_VERSION"));
-                            ui.end_row();
+        if self.show_stage_coordinates {
+            self.stage_coordinates_overlay(egui_ctx);
+        }
 
-                            ui.label(text(&self.locale, "about-ruffle-channel"));
-                            ui.label(env!("CFG_RELEASE_CHANNEL"));
-                            ui.end_row();
+        // Nothing that could lead away from the movie is shown in kiosk mode.
+        if !self.kiosk {
+            self.about_window(egui_ctx);
+            self.preferences_window(egui_ctx);
+            self.movie_info_window(egui_ctx);
+            self.open_url_prompt(egui_ctx);
+            self.error_window(egui_ctx);
+            self.unopened_url_window(egui_ctx);
+            self.update_check_window(egui_ctx);
+            self.add_bookmark_window(egui_ctx);
+            self.bookmarks_manager_window(egui_ctx);
+            self.playlist_window(egui_ctx);
+            self.go_to_frame_window(egui_ctx);
+            self.record_frames_window(egui_ctx);
+            self.recording_window(egui_ctx);
+        }
+        self.exit_confirmation_window(egui_ctx);
 
-                            ui.label(text(&self.locale, "about-ruffle-build-time"));
-                            ui.label(
-                                /*DateTime::parse_from_rfc3339(env!("VERGEN_BUILD_TIMESTAMP"))
-                                    .map(|t| t.format("%c").to_string())
-                                    .unwrap_or_else(|_|*/ env!("VERGEN_BUILD_TIMESTAMP").to_string()//),
-                            );
-                            ui.end_row();
+        if self.is_context_menu_visible {
+            self.context_menu(egui_ctx, is_fullscreen);
+        }
 
-                            ui.label(text(&self.locale, "about-ruffle-commit-ref"));
-                            ui.hyperlink_to(
-                                env!("VERGEN_GIT_SHA"),
-                                format!(
-                                    "https://github.com/ruffle-rs/ruffle/commit/{}",
-                                    env!("VERGEN_GIT_SHA")
-                                ),
-                            );
-                            ui.end_row();
+        if let Some(path) = &self.hovered_file {
+            self.file_drop_overlay(egui_ctx, is_supported_file(path));
+        }
 
-                            ui.label(text(&self.locale, "about-ruffle-commit-time"));
-                            ui.label(
-                                /*DateTime::parse_from_rfc3339(env!("VERGEN_GIT_COMMIT_TIMESTAMP"))
-                                    .map(|t| t.format("%c").to_string())
-                                    .unwrap_or_else(|_| {*/
-                                        env!("VERGEN_GIT_COMMIT_TIMESTAMP").to_string()
-                                    //}),
-                            );
-                            ui.end_row();
+        self.toasts(egui_ctx);
+    }
 
-                            ui.label(text(&self.locale, "about-ruffle-build-features"));
-                            ui.horizontal_wrapped(|ui| {
-                                ui.label(env!("VERGEN_CARGO_FEATURES").replace(',', ", "));
-                            });
-                            ui.end_row();
-                        });
+    /// Records how long the last frame took, for the FPS counter.
+    pub fn set_frame_time(&mut self, dt: Duration) {
+        if self.frame_times.len() == FPS_SAMPLE_COUNT {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(dt);
+    }
 
-                    ui.horizontal(|ui| {
-                        ui.hyperlink_to(
-                            text(&self.locale, "about-ruffle-visit-website"),
-                            "https://ruffle.rs",
-                        );
-                        ui.hyperlink_to(
-                            text(&self.locale, "about-ruffle-visit-github"),
-                            "https://github.com/ruffle-rs/ruffle/",
-                        );
-                        ui.hyperlink_to(
-                            text(&self.locale, "about-ruffle-visit-discord"),
-                            "https://discord.gg/ruffle",
-                        );
-                        ui.hyperlink_to(
-                            text(&self.locale, "about-ruffle-visit-sponsor"),
-                            "https://opencollective.com/ruffle/",
-                        );
-                        ui.shrink_width_to_current();
-                    });
-                })
-            });
+    /// How many frames per second have been rendered lately.
+    fn measured_fps(&self) -> f64 {
+        let total: Duration = self.frame_times.iter().sum();
+        if total.is_zero() {
+            0.0
+        } else {
+            self.frame_times.len() as f64 / total.as_secs_f64()
+        }
     }
 
-    /// Renders the right-click context menu.
-    fn context_menu(&mut self, egui_ctx: &egui::Context) {
-        /*let mut item_clicked = false;
-        let mut menu_visible = false;
-        // TODO: What is the proper way in egui to spawn a random context menu?
-        egui::CentralPanel::default()
-            .frame(Frame::none())
-            .show(egui_ctx, |_| {})
-            .response
-            .context_menu(|ui| {
-                menu_visible = true;
-                for (i, item) in self.context_menu.iter().enumerate() {
-                    if i != 0 && item.separator_before {
-                        ui.separator();
-                    }
-                    let clicked = if item.checked {
-                        Checkbox::new(&mut true, &item.caption).ui(ui).clicked()
-                    } else {
-                        Button::new(&item.caption).ui(ui).clicked()
-                    };
-                    if clicked {
-                        let _ = self
-                            .event_loop
-                            .send_event(RuffleEvent::ContextMenuItemClicked(i));
-                        item_clicked = true;
-                    }
-                }
-            });
+    /// Covers the movie with a play button, which starts playback when the movie is clicked.
+    /// The menu bar is left uncovered, so it stays usable.
+    fn play_overlay(&mut self, egui_ctx: &egui::Context, show_menu: bool) {
+        let top = if show_menu {
+            self.menu_height() as f32 / egui_ctx.pixels_per_point()
+        } else {
+            0.0
+        };
+        let mut movie_rect = egui_ctx.screen_rect();
+        movie_rect.min.y += top;
+        let clicked = Area::new("play_overlay")
+            .fixed_pos(movie_rect.min)
+            .show(egui_ctx, |ui| {
+                let response = ui
+                    .allocate_rect(movie_rect, Sense::click())
+                    .on_hover_text(text(&self.locale, "click-to-play"));
+                let painter = ui.painter();
+                painter.rect_filled(movie_rect, 0.0, Color32::from_black_alpha(64));
+                let center = movie_rect.center();
+                let opacity = if response.hovered() { 0.9 } else { 0.6 };
+                painter.circle_filled(
+                    center,
+                    PLAY_BUTTON_RADIUS,
+                    Color32::from_black_alpha(160).gamma_multiply(opacity),
+                );
+                // A triangle pointing right, nudged so it looks centered within the circle.
+                let size = PLAY_BUTTON_RADIUS * 0.5;
+                let center = center + vec2(size * 0.2, 0.0);
+                painter.add(Shape::convex_polygon(
+                    vec![
+                        center + vec2(-size * 0.8, -size),
+                        center + vec2(size, 0.0),
+                        center + vec2(-size * 0.8, size),
+                    ],
+                    Color32::WHITE.gamma_multiply(opacity),
+                    Stroke::NONE,
+                ));
+                response.clicked()
+            })
+            .inner;
+        if clicked {
+            self.show_play_overlay = false;
+            self.dispatch(RuffleEvent::PlaybackControl(PlaybackCommand::Play));
+        }
+    }
 
-        if item_clicked
-            || !menu_visible
-            || egui_ctx.input_mut(|input| input.consume_key(Modifiers::NONE, Key::Escape))
-        {
-            // Hide menu.
+    pub fn set_kiosk(&mut self, kiosk: bool) {
+        self.kiosk = kiosk;
+        if kiosk {
+            self.hovered_file = None;
             self.context_menu.clear();
-        }*/
+            self.is_context_menu_visible = false;
+        }
     }
 
-    fn open_file(&mut self, ui: &mut egui::Ui) {
-        let _ = self.event_loop.send_event(RuffleEvent::OpenFile);
-        ui.close_menu();
+    pub fn is_kiosk(&self) -> bool {
+        self.kiosk
     }
 
-    fn close_movie(&mut self, ui: &mut egui::Ui) {
-        let _ = self.event_loop.send_event(RuffleEvent::CloseFile);
-        ui.close_menu();
+    pub fn set_play_overlay(&mut self, show_play_overlay: bool) {
+        self.show_play_overlay = show_play_overlay;
     }
 
-    fn open_url_prompt(&mut self, egui_ctx: &egui::Context) {
-        /*let mut close_prompt = false;
-        egui::Window::new(text(&self.locale, "open-url"))
+    /// Renders the measured and configured frame rates in the top right corner of the movie,
+    /// along with the average and longest frame times, as hitches don't show in an average.
+    fn fps_overlay(&self, egui_ctx: &egui::Context, player: Option<&Player>, show_menu: bool) {
+        let mut label = format!("{:.1} FPS", self.measured_fps());
+        if let Some(player) = player {
+            label.push_str(&format!(" / {:.1}", player.frame_rate()));
+        }
+        if let Some(longest) = self.frame_times.iter().max() {
+            let average = self.frame_times.iter().sum::<Duration>() / self.frame_times.len() as u32;
+            label.push_str(&format!(
+                "\n{:.1} ms avg, {:.1} ms max",
+                average.as_secs_f64() * 1000.0,
+                longest.as_secs_f64() * 1000.0
+            ));
+        }
+        let top = if show_menu {
+            self.menu_height() as f32 / egui_ctx.pixels_per_point()
+        } else {
+            0.0
+        };
+        Area::new("fps_overlay")
+            .anchor(Align2::RIGHT_TOP, vec2(-8.0, top + 8.0))
+            .interactable(false)
+            .order(Order::Foreground)
+            .show(egui_ctx, |ui| {
+                Frame::none()
+                    .fill(Color32::from_black_alpha(160))
+                    .rounding(4.0)
+                    .inner_margin(4.0)
+                    .show(ui, |ui| {
+                        ui.label(RichText::new(label).monospace().color(Color32::WHITE));
+                    });
+            });
+    }
+
+    /// Shows the stage coordinates under the pointer next to it, and copies them when the stage is clicked.
+    fn stage_coordinates_overlay(&mut self, egui_ctx: &egui::Context) {
+        let Some(transform) = self.stage_transform else {
+            return;
+        };
+        // Our own menus and windows are in the way of the stage, so nothing is shown over them.
+        if egui_ctx.is_pointer_over_area() {
+            return;
+        }
+        let Some(position) = egui_ctx.input(|input| input.pointer.hover_pos()) else {
+            return;
+        };
+        let stage_position = transform.to_stage(position);
+        let coordinates = format!("{:.1}, {:.1}", stage_position.x, stage_position.y);
+        Area::new("stage_coordinates_overlay")
+            .fixed_pos(position + vec2(16.0, 16.0))
+            .interactable(false)
+            .order(Order::Tooltip)
+            .show(egui_ctx, |ui| {
+                Frame::none()
+                    .fill(Color32::from_black_alpha(160))
+                    .rounding(4.0)
+                    .inner_margin(4.0)
+                    .show(ui, |ui| {
+                        ui.label(RichText::new(&coordinates).monospace().color(Color32::WHITE));
+                    });
+            });
+        if egui_ctx.input(|input| input.pointer.primary_clicked()) {
+            let args = HashMap::from([("coordinates", FluentValue::from(coordinates.clone()))]);
+            let message = text_with_args(&self.locale, "stage-coordinates-copied", &args);
+            self.push_toast(message.into_owned(), ToastLevel::Info);
+            egui_ctx.output_mut(|output| output.copied_text = coordinates);
+        }
+    }
+
+    pub fn set_stage_transform(&mut self, transform: Option<StageTransform>) {
+        self.stage_transform = transform;
+    }
+
+    /// Draws scrollbars along the bottom and right of the movie for each axis it's zoomed in past the window on,
+    /// which move the stage around when their thumbs are dragged.
+    fn scrollbars_overlay(&mut self, egui_ctx: &egui::Context) {
+        // The panels have been laid out by now, so whatever's left of the window is the movie.
+        let movie_rect = egui_ctx.available_rect();
+        let (horizontal, vertical) = self.scrollbars;
+        // Each bar stops short of the corner that the other one would take up.
+        let corner = vec2(
+            vertical.map_or(0.0, |_| SCROLLBAR_WIDTH),
+            horizontal.map_or(0.0, |_| SCROLLBAR_WIDTH),
+        );
+        let mut position = (
+            horizontal.map_or(0.0, |scrollbar| scrollbar.position),
+            vertical.map_or(0.0, |scrollbar| scrollbar.position),
+        );
+        let mut dragged = false;
+        if let Some(scrollbar) = horizontal {
+            let track = Rect::from_min_max(
+                pos2(movie_rect.min.x, movie_rect.max.y - SCROLLBAR_WIDTH),
+                pos2(movie_rect.max.x - corner.x, movie_rect.max.y),
+            );
+            let id = "horizontal_scrollbar";
+            if let Some(dragged_to) = Self::scrollbar(egui_ctx, id, track, scrollbar, 0) {
+                position.0 = dragged_to;
+                dragged = true;
+            }
+        }
+        if let Some(scrollbar) = vertical {
+            let track = Rect::from_min_max(
+                pos2(movie_rect.max.x - SCROLLBAR_WIDTH, movie_rect.min.y),
+                pos2(movie_rect.max.x, movie_rect.max.y - corner.y),
+            );
+            let id = "vertical_scrollbar";
+            if let Some(dragged_to) = Self::scrollbar(egui_ctx, id, track, scrollbar, 1) {
+                position.1 = dragged_to;
+                dragged = true;
+            }
+        }
+        if dragged {
+            self.dispatch(RuffleEvent::ScrollTo(position.0, position.1));
+        }
+    }
+
+    /// Draws one scrollbar along the given track, running along the given axis, 0 for horizontal and 1 for vertical.
+    /// Returns where its thumb was dragged to, if it was.
+    fn scrollbar(
+        egui_ctx: &egui::Context,
+        id: &'static str,
+        track: Rect,
+        scrollbar: Scrollbar,
+        axis: usize,
+    ) -> Option<f64> {
+        let length = track.size()[axis];
+        let thumb_length = (length * scrollbar.visible as f32)
+            .max(2.0 * SCROLLBAR_WIDTH)
+            .min(length);
+        let travel = length - thumb_length;
+        let mut thumb = track;
+        thumb.min[axis] = track.min[axis] + travel * scrollbar.position as f32;
+        thumb.max[axis] = thumb.min[axis] + thumb_length;
+        Area::new(id)
+            .fixed_pos(track.min)
+            .order(Order::Foreground)
+            .show(egui_ctx, |ui| {
+                // The whole track belongs to the scrollbar, so clicks on it don't reach the movie.
+                ui.allocate_rect(track, Sense::hover());
+                let response = ui.interact(thumb, ui.id().with("thumb"), Sense::drag());
+                let painter = ui.painter();
+                painter.rect_filled(track, 0.0, Color32::from_black_alpha(100));
+                painter.rect_filled(
+                    thumb.shrink(2.0),
+                    SCROLLBAR_WIDTH / 2.0,
+                    ui.style().interact(&response).bg_fill,
+                );
+                let delta = response.drag_delta()[axis];
+                (response.dragged() && delta != 0.0 && travel > 0.0)
+                    .then(|| (scrollbar.position + (delta / travel) as f64).clamp(0.0, 1.0))
+            })
+            .inner
+    }
+
+    pub fn set_scrollbars(&mut self, scrollbars: (Option<Scrollbar>, Option<Scrollbar>)) {
+        self.scrollbars = scrollbars;
+    }
+
+    /// Whether the auto-hidden menu bar should currently be shown,
+    /// because the pointer is at the top of the screen or window, or was there very recently.
+    fn is_menu_revealed(&mut self, egui_ctx: &egui::Context) -> bool {
+        let is_revealed = self.menu_hovered_at.is_some();
+        let is_near_menu = match egui_ctx.input(|input| input.pointer.hover_pos()) {
+            // Once revealed, keep the menu bar and any open menus up while the pointer is on them.
+            Some(pos) if is_revealed => {
+                pos.y <= self.menu_height() as f32 / egui_ctx.pixels_per_point()
+                    || egui_ctx.is_pointer_over_area()
+            }
+            Some(pos) => pos.y <= MENU_REVEAL_DISTANCE,
+            None => false,
+        };
+        if is_near_menu {
+            self.menu_hovered_at = Some(Instant::now());
+            return true;
+        }
+        match self.menu_hovered_at {
+            Some(hovered_at) if hovered_at.elapsed() < MENU_HIDE_DELAY => {
+                egui_ctx.request_repaint_after(MENU_HIDE_DELAY - hovered_at.elapsed());
+                true
+            }
+            _ => {
+                self.menu_hovered_at = None;
+                false
+            }
+        }
+    }
+
+    pub fn set_window_focused(&mut self, is_focused: bool) {
+        self.is_window_focused = is_focused;
+    }
+
+    /// Pauses the movie while the window is unfocused, if enabled,
+    /// and resumes it afterwards unless the user paused it themselves.
+    fn auto_pause(&mut self, player: Option<&Player>) {
+        let Some(player) = player else {
+            self.is_auto_paused = false;
+            return;
+        };
+        if !self.is_window_focused {
+            if self.pause_on_unfocus && player.is_playing() && !self.is_auto_paused {
+                self.is_auto_paused = true;
+                self.dispatch(RuffleEvent::PlaybackControl(PlaybackCommand::Pause));
+            }
+        } else if self.is_auto_paused {
+            self.is_auto_paused = false;
+            if !player.is_playing() {
+                self.dispatch(RuffleEvent::PlaybackControl(PlaybackCommand::Play));
+            }
+        }
+    }
+
+    /// Adds a warning about the current movie to the warning banner, unless the user silenced warnings for it.
+    pub fn add_warning(&mut self, message: String) {
+        let is_silenced = match &self.movie_url {
+            Some(url) => self.silenced_warnings.contains(&url.to_string()),
+            None => false,
+        };
+        if !is_silenced && !self.warnings.contains(&message) {
+            self.warnings.push(message);
+        }
+    }
+
+    /// Renders the warnings about the current movie in a strip under the menu bar, one line each.
+    /// It's drawn over the movie rather than pushing it down, so that warnings don't change the movie's layout.
+    fn warning_banner(&mut self, egui_ctx: &egui::Context, show_menu: bool) {
+        let top = if show_menu {
+            self.menu_height() as f32 / egui_ctx.pixels_per_point()
+        } else {
+            0.0
+        };
+        let width = egui_ctx.screen_rect().width();
+        let mut dismissed = None;
+        let mut dismiss_all = false;
+        let mut silence = false;
+        Area::new("warning_banner")
+            .fixed_pos(pos2(0.0, top))
+            .order(Order::Foreground)
+            .show(egui_ctx, |ui| {
+                Frame::side_top_panel(ui.style())
+                    .fill(ui.visuals().extreme_bg_color)
+                    .show(ui, |ui| {
+                        ui.set_width(width - 16.0);
+                        let shown = if self.are_warnings_expanded {
+                            self.warnings.len()
+                        } else {
+                            self.warnings.len().min(MAX_SHOWN_WARNINGS)
+                        };
+                        for (index, warning) in self.warnings.iter().enumerate().take(shown) {
+                            ui.horizontal(|ui| {
+                                ui.colored_label(ui.visuals().warn_fg_color, "⚠");
+                                ui.label(warning.as_str());
+                                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                    if ui
+                                        .small_button("🗙")
+                                        .on_hover_text(text(&self.locale, "warning-dismiss"))
+                                        .clicked()
+                                    {
+                                        dismissed = Some(index);
+                                    }
+                                });
+                            });
+                        }
+                        ui.horizontal(|ui| {
+                            let hidden = self.warnings.len() - shown;
+                            if hidden > 0 {
+                                let args = HashMap::from([("count", FluentValue::from(hidden))]);
+                                if ui
+                                    .link(text_with_args(&self.locale, "warning-show-more", &args))
+                                    .clicked()
+                                {
+                                    self.are_warnings_expanded = true;
+                                }
+                            }
+                            if ui.button(text(&self.locale, "warning-report")).clicked() {
+                                self.launch_website(ui, BUG_REPORT_URL);
+                            }
+                            if self.movie_url.is_some() {
+                                ui.checkbox(
+                                    &mut silence,
+                                    text(&self.locale, "warning-silence-movie"),
+                                );
+                            }
+                            if self.warnings.len() > 1 {
+                                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                    if ui
+                                        .button(text(&self.locale, "warning-dismiss-all"))
+                                        .clicked()
+                                    {
+                                        dismiss_all = true;
+                                    }
+                                });
+                            }
+                        });
+                    });
+            });
+        if let Some(index) = dismissed {
+            self.warnings.remove(index);
+        }
+        if silence {
+            if let Some(url) = &self.movie_url {
+                self.silenced_warnings.push(url.to_string());
+                self.dispatch(RuffleEvent::SetSilencedWarnings(self.silenced_warnings.clone()));
+            }
+        }
+        if dismiss_all || silence {
+            self.warnings.clear();
+        }
+    }
+
+    /// Shows a short message in the bottom corner of the window, which fades away on its own.
+    pub fn push_toast(&mut self, text: String, level: ToastLevel) {
+        self.toasts.push((text, level, Instant::now()));
+    }
+
+    /// Reports the outcome of taking a screenshot.
+    pub fn screenshot_taken(&mut self, result: anyhow::Result<PathBuf>) {
+        let level = ToastLevel::of(&result);
+        let message = match result {
+            Ok(path) => {
+                let args = HashMap::from([("path", FluentValue::from(path.display().to_string()))]);
+                text_with_args(&self.locale, "screenshot-saved", &args)
+            }
+            Err(e) => {
+                tracing::error!("Couldn't save screenshot: {e}");
+                let args = HashMap::from([("error", FluentValue::from(e.to_string()))]);
+                text_with_args(&self.locale, "screenshot-failed", &args)
+            }
+        };
+        self.push_toast(message.into_owned(), level);
+    }
+
+    /// Reports the outcome of copying the current frame to the clipboard.
+    pub fn frame_copied(&mut self, result: anyhow::Result<()>) {
+        let level = ToastLevel::of(&result);
+        let message = match result {
+            Ok(()) => text(&self.locale, "frame-copied"),
+            Err(e) => {
+                tracing::error!("Couldn't copy frame: {e}");
+                let args = HashMap::from([("error", FluentValue::from(e.to_string()))]);
+                text_with_args(&self.locale, "frame-copy-failed", &args)
+            }
+        };
+        self.push_toast(message.into_owned(), level);
+    }
+
+    /// Renders the stack of toasts, dropping the ones that have expired.
+    fn toasts(&mut self, egui_ctx: &egui::Context) {
+        self.toasts
+            .retain(|(_, _, pushed_at)| pushed_at.elapsed() < TOAST_DURATION);
+        if self.toasts.is_empty() {
+            return;
+        }
+        let is_motion_reduced = self.is_motion_reduced();
+        Area::new("toasts")
+            .anchor(Align2::RIGHT_BOTTOM, vec2(-16.0, -16.0))
+            .interactable(false)
+            .order(Order::Tooltip)
+            .show(egui_ctx, |ui| {
+                for (text, level, pushed_at) in &self.toasts {
+                    let remaining = TOAST_DURATION.saturating_sub(pushed_at.elapsed());
+                    let opacity = if is_motion_reduced {
+                        1.0
+                    } else {
+                        (remaining.as_secs_f32() / TOAST_FADE_DURATION.as_secs_f32()).min(1.0)
+                    };
+                    let visuals = ui.visuals();
+                    // Warnings and errors are outlined and written in their color, to stand out.
+                    let level_color = match level {
+                        ToastLevel::Info => None,
+                        ToastLevel::Warning => Some(visuals.warn_fg_color),
+                        ToastLevel::Error => Some(visuals.error_fg_color),
+                    };
+                    let mut frame = Frame::popup(ui.style());
+                    if let Some(color) = level_color {
+                        frame.stroke.color = color;
+                    }
+                    let text_color = level_color.unwrap_or_else(|| visuals.text_color());
+                    frame.fill = frame.fill.gamma_multiply(opacity);
+                    frame.stroke.color = frame.stroke.color.gamma_multiply(opacity);
+                    frame.shadow.color = frame.shadow.color.gamma_multiply(opacity);
+                    frame.show(ui, |ui| {
+                        ui.label(RichText::new(text).color(text_color.gamma_multiply(opacity)));
+                    });
+                }
+            });
+        // Keep repainting while toasts are visible, so that they can fade out.
+        egui_ctx.request_repaint_after(Duration::from_millis(50));
+    }
+
+    /// Renders a hint over the whole window while a file is dragged over it.
+    fn file_drop_overlay(&self, egui_ctx: &egui::Context, is_supported: bool) {
+        let message = if is_supported {
+            text(&self.locale, "drop-to-open")
+        } else {
+            text(&self.locale, "drop-unsupported")
+        };
+        let painter =
+            egui_ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("file_drop_overlay")));
+        let screen_rect = egui_ctx.screen_rect();
+        painter.rect_filled(screen_rect, 0.0, Color32::from_black_alpha(192));
+        painter.text(
+            screen_rect.center(),
+            Align2::CENTER_CENTER,
+            message,
+            TextStyle::Heading.resolve(&egui_ctx.style()),
+            Color32::WHITE,
+        );
+    }
+
+    pub fn set_hovered_file(&mut self, path: Option<PathBuf>) {
+        if !self.kiosk {
+            self.hovered_file = path;
+        }
+    }
+
+    /// Opens a file dropped onto the window, if it's something we can open.
+    pub fn drop_file(&mut self, path: PathBuf) {
+        self.hovered_file = None;
+        if self.kiosk {
+            return;
+        }
+        if is_supported_file(&path) {
+            self.dispatch(RuffleEvent::OpenDroppedFile(path));
+        } else {
+            tracing::warn!("Ignoring dropped file of unsupported type: {}", path.display());
+            let name = path.file_name().map_or_else(
+                || path.display().to_string(),
+                |name| name.to_string_lossy().into_owned(),
+            );
+            let args = HashMap::from([("name", FluentValue::from(name))]);
+            let message = text_with_args(&self.locale, "drop-unsupported-ignored", &args);
+            self.push_toast(message.into_owned(), ToastLevel::Warning);
+        }
+    }
+
+    pub fn show_context_menu(&mut self, menu: Vec<ContextMenuItem>) {
+        if self.kiosk {
+            return;
+        }
+        self.context_menu = menu;
+        self.is_context_menu_visible = true;
+        self.context_menu_position = None;
+    }
+
+    pub fn is_context_menu_visible(&self) -> bool {
+        self.is_context_menu_visible
+    }
+
+    /// Renders the main menu bar at the top of the window.
+    fn main_menu_bar(
+        &mut self,
+        egui_ctx: &egui::Context,
+        player: Option<&Player>,
+        is_fullscreen: bool,
+    ) {
+        let has_movie = player.is_some();
+        let is_playing = player.map_or(false, |player| player.is_playing());
+        let has_text_focus = player.map_or(false, |player| player.has_text_focus());
+        let response = egui::TopBottomPanel::top("menu_bar").show(egui_ctx, |ui| {
+            let file_menu = self.file_menu_items(has_movie);
+            for item in &file_menu {
+                self.menu_item_shortcut(ui, item);
+            }
+            for item in &self.zoom_menu_items() {
+                self.menu_item_shortcut(ui, item);
+            }
+            let (increase_ui_scale, decrease_ui_scale) = ui.ctx().input_mut(|input| {
+                (
+                    input.consume_shortcut(&self.shortcuts.get(ShortcutAction::IncreaseUiScale)),
+                    input.consume_shortcut(&self.shortcuts.get(ShortcutAction::DecreaseUiScale)),
+                )
+            });
+            if increase_ui_scale {
+                if let Some(&ui_scale) = UI_SCALES.iter().find(|&&scale| scale > self.ui_scale) {
+                    self.set_ui_scale(ui_scale);
+                }
+            }
+            if decrease_ui_scale {
+                if let Some(&ui_scale) = UI_SCALES.iter().rev().find(|&&scale| scale < self.ui_scale) {
+                    self.set_ui_scale(ui_scale);
+                }
+            }
+            if ui
+                .ctx()
+                .input_mut(|input| input.consume_shortcut(&CYCLE_LANGUAGE_SHORTCUT))
+            {
+                self.cycle_language();
+            }
+            // Leave these alone while typing into our own text fields, so they keep working there.
+            if has_movie && !ui.ctx().wants_keyboard_input() {
+                // Without a text field in the movie to act on, these keys are left to whatever else wants them.
+                if has_text_focus {
+                    for (command, key, _) in CLIPBOARD_ITEMS {
+                        if ui.ctx().input_mut(|input| {
+                            input.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, key))
+                        }) {
+                            self.clipboard_op(ui, command);
+                        }
+                    }
+                }
+                if self.can_copy_frame
+                    && ui.ctx().input_mut(|input| {
+                        input.consume_shortcut(&self.shortcuts.get(ShortcutAction::CopyFrame))
+                    })
+                {
+                    self.dispatch(RuffleEvent::CopyFrame);
+                }
+            }
+            // Space is the default, which has to keep typing spaces into our own text fields.
+            if has_movie
+                && !ui.ctx().wants_keyboard_input()
+                && ui.ctx().input_mut(|input| {
+                    input.consume_shortcut(&self.shortcuts.get(ShortcutAction::PlayPause))
+                })
+            {
+                self.control_playback(
+                    ui,
+                    if is_playing {
+                        PlaybackCommand::Pause
+                    } else {
+                        PlaybackCommand::Play
+                    },
+                );
+            }
+
+            menu::bar(ui, |ui| {
+                menu::menu_button(ui, text(&self.locale, "file-menu"), |ui| {
+                    let [
+                        open_file,
+                        open_file_in_new_window,
+                        open_url,
+                        reload,
+                        copy_location,
+                        close,
+                        take_screenshot,
+                        exit,
+                    ] = &file_menu;
+                    self.menu_item(ui, open_file);
+                    self.menu_item(ui, open_file_in_new_window);
+                    self.menu_item(ui, open_url);
+                    ui.add_enabled_ui(!self.recent_files.is_empty(), |ui| {
+                        menu::menu_button(ui, text(&self.locale, "file-menu-open-recent"), |ui| {
+                            self.recent_files_menu(ui);
+                        });
+                    });
+                    if Button::new(text(&self.locale, "file-menu-playlist")).ui(ui).clicked() {
+                        self.is_playlist_visible = true;
+                        ui.close_menu();
+                    }
+                    self.menu_item(ui, reload);
+                    self.menu_item(ui, copy_location);
+                    self.menu_item(ui, close);
+                    self.menu_item(ui, take_screenshot);
+                    ui.separator();
+                    self.menu_item(ui, exit);
+                });
+                menu::menu_button(ui, text(&self.locale, "edit-menu"), |ui| {
+                    for (command, key, id) in CLIPBOARD_ITEMS {
+                        let shortcut = KeyboardShortcut::new(Modifiers::COMMAND, key);
+                        let button = Button::new(text(&self.locale, id))
+                            .shortcut_text(ui.ctx().format_shortcut(&shortcut));
+                        if ui.add_enabled(has_text_focus, button).clicked() {
+                            self.clipboard_op(ui, command);
+                        }
+                    }
+                    if self.can_copy_frame {
+                        ui.separator();
+                        let shortcut = self.shortcuts.get(ShortcutAction::CopyFrame);
+                        let button = Button::new(text(&self.locale, "edit-menu-copy-image"))
+                            .shortcut_text(ui.ctx().format_shortcut(&shortcut));
+                        if ui.add_enabled(has_movie, button).clicked() {
+                            self.dispatch(RuffleEvent::CopyFrame);
+                            ui.close_menu();
+                        }
+                    }
+                });
+                menu::menu_button(ui, text(&self.locale, "view-menu"), |ui| {
+                    let mut fullscreen = is_fullscreen;
+                    // Checkboxes have no room for shortcut text, so the rebindable shortcut is shown on hover.
+                    let shortcut = self.shortcuts.get(ShortcutAction::Fullscreen);
+                    if Checkbox::new(&mut fullscreen, text(&self.locale, "view-menu-fullscreen"))
+                        .ui(ui)
+                        .on_hover_text(ui.ctx().format_shortcut(&shortcut))
+                        .clicked()
+                    {
+                        self.toggle_fullscreen();
+                        ui.close_menu();
+                    }
+                    let mut hide_menu_bar = self.hide_menu_bar;
+                    let shortcut = self.shortcuts.get(ShortcutAction::HideMenuBar);
+                    let label = text(&self.locale, "view-menu-hide-menu-bar");
+                    if Checkbox::new(&mut hide_menu_bar, label)
+                        .ui(ui)
+                        .on_hover_text(ui.ctx().format_shortcut(&shortcut))
+                        .clicked()
+                    {
+                        self.set_hide_menu_bar(hide_menu_bar);
+                        ui.close_menu();
+                    }
+                    let mut always_on_top = self.always_on_top;
+                    let label = text(&self.locale, "view-menu-always-on-top");
+                    if Checkbox::new(&mut always_on_top, label)
+                        .ui(ui)
+                        .clicked()
+                    {
+                        self.always_on_top = always_on_top;
+                        self.dispatch(RuffleEvent::SetAlwaysOnTop(always_on_top));
+                        ui.close_menu();
+                    }
+                    menu::menu_button(ui, text(&self.locale, "view-menu-quality"), |ui| {
+                        self.quality_menu(ui);
+                    });
+                    menu::menu_button(ui, text(&self.locale, "view-menu-scale-mode"), |ui| {
+                        self.scale_mode_menu(ui);
+                    });
+                    menu::menu_button(ui, text(&self.locale, "view-menu-letterbox"), |ui| {
+                        self.letterbox_menu(ui);
+                    });
+                    let percent = (self.zoom * 100.0).round();
+                    let args = HashMap::from([("percent", FluentValue::from(percent as f64))]);
+                    menu::menu_button(ui, text_with_args(&self.locale, "view-menu-zoom", &args), |ui| {
+                        self.zoom_menu(ui);
+                    });
+                    ui.separator();
+                    if ui
+                        .add_enabled(
+                            has_movie && !is_fullscreen,
+                            Button::new(text(&self.locale, "view-menu-match-window-size")),
+                        )
+                        .clicked()
+                    {
+                        self.dispatch(RuffleEvent::MatchWindowSize);
+                        ui.close_menu();
+                    }
+                    if Button::new(text(&self.locale, "view-menu-movie-info")).ui(ui).clicked() {
+                        self.is_movie_info_visible = true;
+                        ui.close_menu();
+                    }
+                    let label = text(&self.locale, "view-menu-show-status-bar");
+                    if Checkbox::new(&mut self.show_status_bar, label)
+                        .ui(ui)
+                        .clicked()
+                    {
+                        ui.close_menu();
+                    }
+                });
+                ui.add_enabled_ui(has_movie, |ui| {
+                    menu::menu_button(ui, text(&self.locale, "controls-menu"), |ui| {
+                        let shortcut = self.shortcuts.get(ShortcutAction::PlayPause);
+                        let (label, command) = if is_playing {
+                            ("controls-menu-pause", PlaybackCommand::Pause)
+                        } else {
+                            ("controls-menu-resume", PlaybackCommand::Play)
+                        };
+                        if Button::new(text(&self.locale, label))
+                            .shortcut_text(ui.ctx().format_shortcut(&shortcut))
+                            .ui(ui)
+                            .clicked()
+                        {
+                            self.control_playback(ui, command);
+                        }
+                        // Stepping while playing would be overtaken by the next frame straight away.
+                        if ui
+                            .add_enabled(
+                                !is_playing,
+                                Button::new(text(&self.locale, "controls-menu-step-frame")),
+                            )
+                            .clicked()
+                        {
+                            self.control_playback(ui, PlaybackCommand::StepForward);
+                        }
+                        if Button::new(text(&self.locale, "controls-menu-rewind"))
+                            .ui(ui)
+                            .clicked()
+                        {
+                            self.control_playback(ui, PlaybackCommand::Rewind);
+                        }
+
+                        ui.separator();
+
+                        let previous = self.previous_playlist_index();
+                        if ui
+                            .add_enabled(
+                                previous.is_some(),
+                                Button::new(text(&self.locale, "controls-menu-previous")),
+                            )
+                            .clicked()
+                        {
+                            if let Some(index) = previous {
+                                self.play_playlist_entry(index);
+                            }
+                            ui.close_menu();
+                        }
+                        let next = self.next_playlist_index();
+                        if ui
+                            .add_enabled(
+                                next.is_some(),
+                                Button::new(text(&self.locale, "controls-menu-next")),
+                            )
+                            .clicked()
+                        {
+                            if let Some(index) = next {
+                                self.play_playlist_entry(index);
+                            }
+                            ui.close_menu();
+                        }
+
+                        ui.separator();
+
+                        let mut percent = (self.volume * 100.0).round();
+                        let volume_changed = ui
+                            .add(
+                                Slider::new(&mut percent, 0.0..=100.0)
+                                    .suffix("%")
+                                    .text(text(&self.locale, "controls-menu-volume")),
+                            )
+                            .changed();
+                        if volume_changed {
+                            self.volume = percent / 100.0;
+                            // Dragging the slider implies the user wants to hear the result.
+                            self.is_muted = false;
+                        }
+                        let label = text(&self.locale, "controls-menu-mute");
+                        let mute_changed = ui.checkbox(&mut self.is_muted, label).changed();
+                        if volume_changed || mute_changed {
+                            self.send_volume();
+                        }
+                    });
+                });
+                menu::menu_button(ui, text(&self.locale, "bookmarks-menu"), |ui| {
+                    self.bookmarks_menu(ui);
+                });
+                menu::menu_button(ui, text(&self.locale, "settings-menu"), |ui| {
+                    menu::menu_button(ui, text(&self.locale, "settings-menu-language"), |ui| {
+                        self.language_menu(ui);
+                    });
+                    menu::menu_button(ui, text(&self.locale, "settings-menu-theme"), |ui| {
+                        self.theme_menu(ui);
+                    });
+                    let mut auto_hide_menu = self.auto_hide_menu;
+                    if Checkbox::new(
+                        &mut auto_hide_menu,
+                        text(&self.locale, "settings-menu-auto-hide-menu"),
+                    )
+                    .ui(ui)
+                    .clicked()
+                    {
+                        self.set_auto_hide_menu(auto_hide_menu);
+                        ui.close_menu();
+                    }
+                    let mut pause_on_unfocus = self.pause_on_unfocus;
+                    if Checkbox::new(
+                        &mut pause_on_unfocus,
+                        text(&self.locale, "settings-menu-pause-on-unfocus"),
+                    )
+                    .ui(ui)
+                    .clicked()
+                    {
+                        self.set_pause_on_unfocus(pause_on_unfocus);
+                        ui.close_menu();
+                    }
+                    let mut confirm_exit = self.confirm_exit;
+                    if Checkbox::new(
+                        &mut confirm_exit,
+                        text(&self.locale, "settings-menu-confirm-exit"),
+                    )
+                    .ui(ui)
+                    .clicked()
+                    {
+                        self.set_confirm_exit(confirm_exit);
+                        ui.close_menu();
+                    }
+                    let shortcut = self.shortcuts.get(ShortcutAction::Kiosk);
+                    if Button::new(text(&self.locale, "settings-menu-kiosk"))
+                        .shortcut_text(ui.ctx().format_shortcut(&shortcut))
+                        .ui(ui)
+                        .clicked()
+                    {
+                        self.dispatch(RuffleEvent::SetKiosk(true));
+                        ui.close_menu();
+                    }
+                    if ui
+                        .add_enabled(
+                            has_movie,
+                            Button::new(text(&self.locale, "settings-menu-reset-movie-settings")),
+                        )
+                        .on_hover_text(text(
+                            &self.locale,
+                            "settings-menu-reset-movie-settings-tooltip",
+                        ))
+                        .clicked()
+                    {
+                        self.dispatch(RuffleEvent::ResetMovieSettings);
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if Button::new(text(&self.locale, "settings-menu-preferences"))
+                        .shortcut_text(preferences_shortcut_text(ui.ctx()))
+                        .ui(ui)
+                        .clicked()
+                    {
+                        self.show_preferences();
+                        ui.close_menu();
+                    }
+                });
+                menu::menu_button(ui, text(&self.locale, "debug-menu"), |ui| {
+                    let label = text(&self.locale, "debug-menu-show-fps");
+                    if Checkbox::new(&mut self.show_fps, label)
+                        .ui(ui)
+                        .clicked()
+                    {
+                        ui.close_menu();
+                    }
+                    let label = text(&self.locale, "debug-menu-show-console");
+                    if Checkbox::new(&mut self.is_console_visible, label)
+                        .ui(ui)
+                        .clicked()
+                    {
+                        ui.close_menu();
+                    }
+                    let label = text(&self.locale, "debug-menu-show-stage-coordinates");
+                    if Checkbox::new(&mut self.show_stage_coordinates, label)
+                        .ui(ui)
+                        .on_hover_text(text(
+                            &self.locale,
+                            "debug-menu-show-stage-coordinates-tooltip",
+                        ))
+                        .clicked()
+                    {
+                        ui.close_menu();
+                    }
+                    let label = text(&self.locale, "debug-menu-watch-file");
+                    if Checkbox::new(&mut self.watch_file, label)
+                        .ui(ui)
+                        .on_hover_text(text(&self.locale, "debug-menu-watch-file-tooltip"))
+                        .clicked()
+                    {
+                        self.dispatch(RuffleEvent::SetWatchFile(self.watch_file));
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if ui
+                        .add_enabled(
+                            has_movie,
+                            Button::new(text(&self.locale, "debug-menu-go-to-frame")),
+                        )
+                        .clicked()
+                    {
+                        self.go_to_frame_text = Some(String::new());
+                        self.go_to_frame_error = None;
+                        ui.close_menu();
+                    }
+                    if ui
+                        .add_enabled(
+                            has_movie,
+                            Button::new(text(&self.locale, "debug-menu-previous-frame"))
+                                .shortcut_text("["),
+                        )
+                        .clicked()
+                    {
+                        self.control_playback(ui, PlaybackCommand::StepBackward);
+                    }
+                    if ui
+                        .add_enabled(
+                            has_movie,
+                            Button::new(text(&self.locale, "debug-menu-next-frame"))
+                                .shortcut_text("]"),
+                        )
+                        .clicked()
+                    {
+                        self.control_playback(ui, PlaybackCommand::StepForward);
+                    }
+                    ui.separator();
+                    if ui
+                        .add_enabled(
+                            has_movie && self.recording_progress.is_none(),
+                            Button::new(text(&self.locale, "debug-menu-record-frames")),
+                        )
+                        .clicked()
+                    {
+                        let num_frames = self
+                            .movie_metadata
+                            .as_ref()
+                            .map_or(1, |metadata| u32::from(metadata.num_frames));
+                        self.record_frames_count = Some(num_frames.clamp(1, MAX_RECORDED_FRAMES));
+                        ui.close_menu();
+                    }
+                });
+                menu::menu_button(ui, text(&self.locale, "help-menu"), |ui| {
+                    if ui.button(text(&self.locale, "help-menu-join-discord")).clicked() {
+                        self.launch_website(ui, "https://discord.gg/ruffle");
+                    }
+                    if ui.button(text(&self.locale, "help-menu-report-a-bug")).clicked() {
+                        self.launch_website(ui, BUG_REPORT_URL);
+                    }
+                    if ui.button(text(&self.locale, "help-menu-sponsor-development")).clicked() {
+                        self.launch_website(ui, "https://opencollective.com/ruffle/");
+                    }
+                    if ui.button(text(&self.locale, "help-menu-translate-ruffle")).clicked() {
+                        self.launch_website(ui, "https://crowdin.com/project/ruffle");
+                    }
+                    if ui.button(text(&self.locale, "help-menu-check-for-updates")).clicked() {
+                        self.check_for_updates(false);
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if ui.button(text(&self.locale, "help-menu-open-data-folder")).clicked() {
+                        if let Some(dir) = config_dir() {
+                            // It's only created once something is saved, so make sure there's something to open.
+                            if let Err(e) = std::fs::create_dir_all(&dir) {
+                                tracing::warn!("Couldn't create {}: {e}", dir.display());
+                            }
+                            self.open_path(&dir);
+                        }
+                        ui.close_menu();
+                    }
+                    // It may have failed to be created, in which case there's nothing to open.
+                    let log_file = log_file().filter(|path| path.is_file());
+                    if ui
+                        .add_enabled(
+                            log_file.is_some(),
+                            Button::new(text(&self.locale, "help-menu-open-log-file")),
+                        )
+                        .clicked()
+                    {
+                        if let Some(log_file) = log_file {
+                            self.open_path(&log_file);
+                        }
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if ui.button(text(&self.locale, "help-menu-about")).clicked() {
+                        self.show_about_screen(ui);
+                    }
+                });
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    let hover_text = if self.is_muted {
+                        text(&self.locale, "menu-bar-unmute")
+                    } else {
+                        text(&self.locale, "menu-bar-mute")
+                    };
+                    if Button::new(volume_icon(self.volume, self.is_muted))
+                        .frame(false)
+                        .ui(ui)
+                        .on_hover_text(hover_text)
+                        .clicked()
+                    {
+                        self.is_muted = !self.is_muted;
+                        self.send_volume();
+                    }
+                });
+            });
+        });
+        let height = response.response.rect.height() * egui_ctx.pixels_per_point();
+        self.menu_height = Some(height.ceil() as u32);
+    }
+
+    /// The height of the menu bar in physical pixels, which is the offset at which the movie is shown
+    /// and what's added to the window size when trying to match a movie.
+    /// This depends on the theme, font size and scale factor, so it's only known once the menu has been shown.
+    pub fn menu_height(&self) -> u32 {
+        self.menu_height
+            .unwrap_or_else(|| (MENU_HEIGHT as f32 * self.ui_scale).round() as u32)
+    }
+
+    /// Renders a strip along the bottom of the window with the playback state, frame and frame rate.
+    fn status_bar(&mut self, egui_ctx: &egui::Context, player: Option<&Player>) {
+        let response = TopBottomPanel::bottom("status_bar").show(egui_ctx, |ui| {
+            ui.horizontal(|ui| {
+                let Some(player) = player else {
+                    ui.label(text(&self.locale, "status-bar-no-movie"));
+                    return;
+                };
+                if player.is_playing() {
+                    ui.label(text(&self.locale, "status-bar-playing"));
+                } else {
+                    ui.label(text(&self.locale, "status-bar-paused"));
+                }
+                ui.separator();
+                let args = HashMap::from([
+                    ("current", FluentValue::from(player.current_frame() + 1)),
+                    ("total", FluentValue::from(player.num_frames())),
+                ]);
+                ui.label(text_with_args(&self.locale, "status-bar-frame", &args));
+                ui.separator();
+                let args = HashMap::from([("rate", FluentValue::from(player.frame_rate()))]);
+                ui.label(text_with_args(&self.locale, "status-bar-frame-rate", &args));
+            });
+        });
+        let height = response.response.rect.height() * egui_ctx.pixels_per_point();
+        self.status_bar_height = Some(height.ceil() as u32);
+    }
+
+    /// The height of the status bar and the console in physical pixels, or 0 if they're hidden.
+    /// Like the menu bar, they're kept clear of the movie.
+    pub fn bottom_panel_height(&self) -> u32 {
+        self.status_bar_height.unwrap_or(0) + self.console_height.unwrap_or(0)
+    }
+
+    /// Appends a line to the console, dropping the oldest lines past the limit.
+    fn add_console_line(&mut self, line: ConsoleLine) {
+        if self.console_lines.len() == MAX_CONSOLE_LINES {
+            self.console_lines.pop_front();
+        }
+        self.console_lines.push_back(line);
+    }
+
+    /// Renders the console docked to the bottom of the window, above the status bar.
+    fn console_panel(&mut self, egui_ctx: &egui::Context) {
+        let mut clear = false;
+        let mut copy = false;
+        let mut save = false;
+        let mut saved_text = None;
+        let search = self.console_search.to_lowercase();
+        let response = TopBottomPanel::bottom("console")
+            .resizable(true)
+            .default_height(200.0)
+            .show(egui_ctx, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    for (level, id) in CONSOLE_LEVELS {
+                        let mut is_shown = self.console_levels.contains(&level);
+                        if ui.checkbox(&mut is_shown, text(&self.locale, id)).changed() {
+                            if is_shown {
+                                self.console_levels.push(level);
+                            } else {
+                                self.console_levels.retain(|&shown| shown != level);
+                            }
+                        }
+                    }
+                    ui.separator();
+                    let hint = text(&self.locale, "console-search");
+                    TextEdit::singleline(&mut self.console_search)
+                        .hint_text(hint)
+                        .desired_width(160.0)
+                        .ui(ui);
+                    let label = text(&self.locale, "console-wrap");
+                    ui.checkbox(&mut self.console_wrap, label);
+                    ui.separator();
+                    clear = ui.button(text(&self.locale, "console-clear")).clicked();
+                    copy = ui
+                        .button(text(&self.locale, "console-copy-visible"))
+                        .clicked();
+                    save = ui.button(text(&self.locale, "console-save")).clicked();
+                });
+                ui.separator();
+                let lines: Vec<&ConsoleLine> = self
+                    .console_lines
+                    .iter()
+                    .filter(|line| {
+                        self.console_levels.contains(&line.level)
+                            && (search.is_empty() || line.text.to_lowercase().contains(&search))
+                    })
+                    .collect();
+                let label = |ui: &mut egui::Ui, line: &ConsoleLine| {
+                    let mut text = RichText::new(&line.text).monospace();
+                    match line.level {
+                        ConsoleLevel::Error => text = text.color(ui.visuals().error_fg_color),
+                        ConsoleLevel::Warn => text = text.color(ui.visuals().warn_fg_color),
+                        ConsoleLevel::Debug => text = text.weak(),
+                        ConsoleLevel::Info => {}
+                    }
+                    Label::new(text).wrap(self.console_wrap).ui(ui);
+                };
+                // Sticking to the bottom follows new lines, until the user scrolls up.
+                if self.console_wrap {
+                    // Wrapped lines differ in height, so they can't be laid out only as they're scrolled to.
+                    ScrollArea::vertical()
+                        .auto_shrink([false, false])
+                        .stick_to_bottom(true)
+                        .show(ui, |ui| {
+                            for &line in &lines {
+                                label(ui, line);
+                            }
+                        });
+                } else {
+                    let row_height = ui.text_style_height(&TextStyle::Monospace);
+                    ScrollArea::both()
+                        .auto_shrink([false, false])
+                        .stick_to_bottom(true)
+                        .show_rows(ui, row_height, lines.len(), |ui, rows| {
+                            for &line in &lines[rows] {
+                                label(ui, line);
+                            }
+                        });
+                }
+                if copy || save {
+                    let visible_text = lines
+                        .iter()
+                        .map(|line| line.text.as_str())
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    if copy {
+                        ui.output_mut(|output| output.copied_text = visible_text.clone());
+                    }
+                    if save {
+                        saved_text = Some(visible_text);
+                    }
+                }
+            });
+        if let Some(text) = saved_text {
+            self.dispatch(RuffleEvent::SaveConsoleLog(text));
+        }
+        let height = response.response.rect.height() * egui_ctx.pixels_per_point();
+        self.console_height = Some(height.ceil() as u32);
+        if clear {
+            self.console_lines.clear();
+        }
+        // Log messages don't wake the GUI, so it looks for new ones every so often.
+        egui_ctx.request_repaint_after(Duration::from_millis(250));
+    }
+
+    /// Reports the outcome of saving the console's lines to a file.
+    pub fn console_log_saved(&mut self, result: anyhow::Result<PathBuf>) {
+        let level = ToastLevel::of(&result);
+        let message = match result {
+            Ok(path) => {
+                let args = HashMap::from([("path", FluentValue::from(path.display().to_string()))]);
+                text_with_args(&self.locale, "console-saved", &args)
+            }
+            Err(e) => {
+                tracing::error!("Couldn't save console output: {e}");
+                let args = HashMap::from([("error", FluentValue::from(e.to_string()))]);
+                text_with_args(&self.locale, "console-save-failed", &args)
+            }
+        };
+        self.push_toast(message.into_owned(), level);
+    }
+
+    pub fn set_movie_metadata(&mut self, metadata: Option<MovieMetadata>) {
+        if metadata.is_none() {
+            self.show_play_overlay = false;
+            self.movie_url = None;
+        }
+        self.movie_metadata = metadata;
+    }
+
+    fn movie_info_window(&mut self, egui_ctx: &egui::Context) {
+        // A copy, so that the window's contents can use `self` while the window holds on to it.
+        let mut is_open = self.is_movie_info_visible;
+        egui::Window::new(text(&self.locale, "movie-info"))
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut is_open)
+            .show(egui_ctx, |ui| {
+                let Some(metadata) = &self.movie_metadata else {
+                    ui.label(RichText::new(text(&self.locale, "movie-info-no-movie")).weak());
+                    return;
+                };
+                Grid::new("movie_info").striped(true).show(ui, |ui| {
+                    ui.label(text(&self.locale, "movie-info-dimensions"));
+                    ui.label(format!("{} × {}", metadata.width, metadata.height));
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "movie-info-frame-rate"));
+                    ui.label(metadata.frame_rate.to_string());
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "movie-info-frame-count"));
+                    ui.label(metadata.num_frames.to_string());
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "movie-info-swf-version"));
+                    ui.label(metadata.version.to_string());
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "movie-info-compression"));
+                    ui.label(match metadata.compression {
+                        swf::Compression::None => text(&self.locale, "movie-info-compression-none"),
+                        swf::Compression::Zlib => Cow::Borrowed("zlib"),
+                        swf::Compression::Lzma => Cow::Borrowed("LZMA"),
+                    });
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "movie-info-background-color"));
+                    ui.horizontal(|ui| {
+                        let color = metadata.background_color;
+                        color_picker::show_color(
+                            ui,
+                            Color32::from_rgb(color.r, color.g, color.b),
+                            vec2(16.0, 16.0),
+                        );
+                        ui.monospace(format!("#{:02X}{:02X}{:02X}", color.r, color.g, color.b));
+                    });
+                    ui.end_row();
+
+                    if let Some(file_size) = metadata.file_size {
+                        ui.label(text(&self.locale, "movie-info-file-size"));
+                        let kilobytes = format!("{:.1}", file_size as f64 / 1024.0);
+                        let args = HashMap::from([
+                            ("kilobytes", FluentValue::from(kilobytes)),
+                            ("bytes", FluentValue::from(file_size)),
+                        ]);
+                        ui.label(text_with_args(&self.locale, "movie-info-file-size-value", &args));
+                        ui.end_row();
+                    }
+                });
+            });
+        self.is_movie_info_visible = is_open;
+    }
+
+    pub fn show_preferences(&mut self) {
+        self.is_preferences_visible = true;
+    }
+
+    fn set_pause_on_unfocus(&mut self, pause_on_unfocus: bool) {
+        self.pause_on_unfocus = pause_on_unfocus;
+        self.dispatch(RuffleEvent::SetPauseOnUnfocus(pause_on_unfocus));
+    }
+
+    fn set_auto_hide_menu(&mut self, auto_hide_menu: bool) {
+        self.auto_hide_menu = auto_hide_menu;
+        self.dispatch(RuffleEvent::SetAutoHideMenu(auto_hide_menu));
+    }
+
+    fn set_hide_menu_bar(&mut self, hide_menu_bar: bool) {
+        self.hide_menu_bar = hide_menu_bar;
+        self.dispatch(RuffleEvent::SetHideMenuBar(hide_menu_bar));
+    }
+
+    /// Whether the menu bar is hidden in a window, with the movie shown over the whole window.
+    pub fn is_menu_bar_hidden(&self) -> bool {
+        self.hide_menu_bar
+    }
+
+    /// Renders the contents of the "Theme" submenu.
+    fn theme_menu(&mut self, ui: &mut egui::Ui) {
+        for (theme, id) in THEME_OPTIONS {
+            if ui
+                .radio(self.theme == theme, text(&self.locale, id))
+                .clicked()
+            {
+                self.set_theme(theme);
+                ui.close_menu();
+            }
+        }
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.dispatch(RuffleEvent::SetTheme(theme));
+    }
+
+    pub fn set_system_theme(&mut self, theme: Option<winit::window::Theme>) {
+        self.system_theme = theme;
+    }
+
+    pub fn set_preferences_saved(&mut self, saved: bool) {
+        self.preferences_saved = saved;
+    }
+
+    /// The visuals for the chosen theme, which every window and menu is drawn with.
+    fn visuals(&self) -> Visuals {
+        match self.theme {
+            Theme::System => match self.system_theme {
+                Some(winit::window::Theme::Light) => Visuals::light(),
+                Some(winit::window::Theme::Dark) | None => Visuals::dark(),
+            },
+            Theme::Light => Visuals::light(),
+            Theme::Dark => Visuals::dark(),
+            Theme::HighContrast => high_contrast_visuals(),
+        }
+    }
+
+    fn set_forward_mouse_wheel(&mut self, forward_mouse_wheel: bool) {
+        self.forward_mouse_wheel = forward_mouse_wheel;
+        self.dispatch(RuffleEvent::SetForwardMouseWheel(forward_mouse_wheel));
+    }
+
+    fn set_autoplay_policy(&mut self, autoplay: AutoplayPolicy) {
+        self.autoplay = autoplay;
+        self.dispatch(RuffleEvent::SetAutoplayPolicy(autoplay));
+    }
+
+    fn select_max_fps(&mut self, max_fps: MaxFps) {
+        self.max_fps = max_fps;
+        self.dispatch(RuffleEvent::SetMaxFps(max_fps));
+    }
+
+    fn set_check_for_updates(&mut self, check_for_updates: bool) {
+        self.check_for_updates = check_for_updates;
+        self.dispatch(RuffleEvent::SetCheckForUpdates(check_for_updates));
+    }
+
+    /// How much larger than usual our own menus and dialogs are drawn.
+    pub fn ui_scale(&self) -> f32 {
+        self.ui_scale
+    }
+
+    fn set_ui_scale(&mut self, ui_scale: f32) {
+        self.ui_scale = ui_scale.clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end());
+        self.dispatch(RuffleEvent::SetUiScale(self.ui_scale));
+    }
+
+    fn set_reduce_motion(&mut self, reduce_motion: ReduceMotion) {
+        self.reduce_motion = reduce_motion;
+        self.dispatch(RuffleEvent::SetReduceMotion(reduce_motion));
+    }
+
+    pub fn set_system_reduces_motion(&mut self, system_reduces_motion: bool) {
+        self.system_reduces_motion = system_reduces_motion;
+    }
+
+    /// Whether the GUI's animations are turned off, either by the user or by following the system.
+    fn is_motion_reduced(&self) -> bool {
+        match self.reduce_motion {
+            ReduceMotion::System => self.system_reduces_motion,
+            ReduceMotion::On => true,
+            ReduceMotion::Off => false,
+        }
+    }
+
+    fn set_start_fullscreen(&mut self, start_fullscreen: bool) {
+        self.start_fullscreen = start_fullscreen;
+        self.dispatch(RuffleEvent::SetStartFullscreen(start_fullscreen));
+    }
+
+    fn set_confirm_exit(&mut self, confirm_exit: bool) {
+        self.confirm_exit = confirm_exit;
+        self.dispatch(RuffleEvent::SetConfirmExit(confirm_exit));
+    }
+
+    fn select_quality(&mut self, quality: StageQuality) {
+        self.quality = quality;
+        self.dispatch(RuffleEvent::SetQuality(quality));
+    }
+
+    fn select_default_quality(&mut self, quality: StageQuality) {
+        self.default_quality = quality;
+        self.dispatch(RuffleEvent::SetDefaultQuality(quality));
+    }
+
+    fn preferences_window(&mut self, egui_ctx: &egui::Context) {
+        if !self.is_preferences_visible {
+            return;
+        }
+        let mut is_open = true;
+        egui::Window::new(text(&self.locale, "preferences"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .open(&mut is_open)
+            .show(egui_ctx, |ui| {
+                if ui
+                    .ctx()
+                    .input_mut(|input| input.consume_key(Modifiers::NONE, Key::Escape))
+                {
+                    self.is_preferences_visible = false;
+                }
+
+                if !self.preferences_saved {
+                    ui.colored_label(
+                        ui.visuals().warn_fg_color,
+                        text(&self.locale, "preferences-not-saved"),
+                    );
+                    ui.separator();
+                }
+
+                ui.heading(text(&self.locale, "preferences-general"));
+                Grid::new("preferences_general").num_columns(2).show(ui, |ui| {
+                    ui.label(text(&self.locale, "preferences-language"));
+                    self.language_combo_box(ui);
+                    ui.end_row();
+
+                    let mut check_for_updates = self.check_for_updates;
+                    ui.label("");
+                    if ui
+                        .checkbox(
+                            &mut check_for_updates,
+                            text(&self.locale, "preferences-check-for-updates"),
+                        )
+                        .clicked()
+                    {
+                        self.set_check_for_updates(check_for_updates);
+                    }
+                    ui.end_row();
+                });
+                ui.separator();
+
+                ui.heading(text(&self.locale, "preferences-playback"));
+                Grid::new("preferences_playback").num_columns(2).show(ui, |ui| {
+                    ui.label(text(&self.locale, "preferences-quality"));
+                    let selected = QUALITY_OPTIONS
+                        .iter()
+                        .find(|(quality, _)| *quality == self.default_quality)
+                        .map_or(Cow::Borrowed(""), |&(_, id)| text(&self.locale, id));
+                    ComboBox::from_id_source("preferences_quality")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            for (quality, id) in QUALITY_OPTIONS {
+                                if ui
+                                    .selectable_label(
+                                        self.default_quality == quality,
+                                        text(&self.locale, id),
+                                    )
+                                    .clicked()
+                                {
+                                    self.select_default_quality(quality);
+                                }
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "preferences-volume"));
+                    ui.horizontal(|ui| {
+                        let mut percent = (self.default_volume * 100.0).round();
+                        let volume_changed = ui
+                            .add(Slider::new(&mut percent, 0.0..=100.0).suffix("%"))
+                            .changed();
+                        if volume_changed {
+                            self.default_volume = percent / 100.0;
+                            self.is_muted = false;
+                        }
+                        let label = text(&self.locale, "preferences-mute");
+                        let mute_changed = ui.checkbox(&mut self.is_muted, label).changed();
+                        if volume_changed || mute_changed {
+                            self.send_default_volume();
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "preferences-autoplay"));
+                    let selected = AUTOPLAY_OPTIONS
+                        .iter()
+                        .find(|(autoplay, _)| *autoplay == self.autoplay)
+                        .map_or(Cow::Borrowed(""), |&(_, id)| text(&self.locale, id));
+                    ComboBox::from_id_source("preferences_autoplay")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            for (autoplay, id) in AUTOPLAY_OPTIONS {
+                                if ui
+                                    .selectable_label(
+                                        self.autoplay == autoplay,
+                                        text(&self.locale, id),
+                                    )
+                                    .clicked()
+                                {
+                                    self.set_autoplay_policy(autoplay);
+                                }
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "preferences-max-fps"));
+                    ui.horizontal(|ui| {
+                        let selected = MAX_FPS_OPTIONS
+                            .iter()
+                            .find(|(max_fps, _)| *max_fps == self.max_fps)
+                            .map_or(Cow::Borrowed(""), |&(_, id)| text(&self.locale, id));
+                        ComboBox::from_id_source("preferences_max_fps")
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                for (max_fps, id) in MAX_FPS_OPTIONS {
+                                    if ui
+                                        .selectable_label(
+                                            self.max_fps == max_fps,
+                                            text(&self.locale, id),
+                                        )
+                                        .clicked()
+                                    {
+                                        self.select_max_fps(max_fps);
+                                    }
+                                }
+                            });
+                        // Lets the effect of the limit be seen while picking it.
+                        if self.show_fps {
+                            let fps = format!("{:.1}", self.measured_fps());
+                            let args = HashMap::from([("fps", FluentValue::from(fps))]);
+                            ui.weak(text_with_args(&self.locale, "preferences-max-fps-current", &args));
+                        }
+                    });
+                    ui.end_row();
+
+                    let mut pause_on_unfocus = self.pause_on_unfocus;
+                    ui.label("");
+                    if ui
+                        .checkbox(
+                            &mut pause_on_unfocus,
+                            text(&self.locale, "settings-menu-pause-on-unfocus"),
+                        )
+                        .clicked()
+                    {
+                        self.set_pause_on_unfocus(pause_on_unfocus);
+                    }
+                    ui.end_row();
+
+                    let mut forward_mouse_wheel = self.forward_mouse_wheel;
+                    ui.label("");
+                    if ui
+                        .checkbox(
+                            &mut forward_mouse_wheel,
+                            text(&self.locale, "preferences-forward-mouse-wheel"),
+                        )
+                        .on_hover_text(text(
+                            &self.locale,
+                            "preferences-forward-mouse-wheel-tooltip",
+                        ))
+                        .clicked()
+                    {
+                        self.set_forward_mouse_wheel(forward_mouse_wheel);
+                    }
+                    ui.end_row();
+                });
+                ui.separator();
+
+                ui.heading(text(&self.locale, "preferences-interface"));
+                Grid::new("preferences_interface").num_columns(2).show(ui, |ui| {
+                    ui.label(text(&self.locale, "preferences-theme"));
+                    let selected = THEME_OPTIONS
+                        .iter()
+                        .find(|(theme, _)| *theme == self.theme)
+                        .map_or(Cow::Borrowed(""), |&(_, id)| text(&self.locale, id));
+                    ComboBox::from_id_source("preferences_theme")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            for (theme, id) in THEME_OPTIONS {
+                                if ui
+                                    .selectable_label(self.theme == theme, text(&self.locale, id))
+                                    .clicked()
+                                {
+                                    self.set_theme(theme);
+                                }
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "preferences-ui-scale"));
+                    ComboBox::from_id_source("preferences_ui_scale")
+                        .selected_text(format!("{}%", (self.ui_scale * 100.0).round()))
+                        .show_ui(ui, |ui| {
+                            for ui_scale in UI_SCALES {
+                                if ui
+                                    .selectable_label(
+                                        self.ui_scale == ui_scale,
+                                        format!("{}%", ui_scale * 100.0),
+                                    )
+                                    .clicked()
+                                {
+                                    self.set_ui_scale(ui_scale);
+                                }
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "preferences-reduce-motion"))
+                        .on_hover_text(text(&self.locale, "preferences-reduce-motion-tooltip"));
+                    let selected = REDUCE_MOTION_OPTIONS
+                        .iter()
+                        .find(|(reduce_motion, _)| *reduce_motion == self.reduce_motion)
+                        .map_or(Cow::Borrowed(""), |&(_, id)| text(&self.locale, id));
+                    ComboBox::from_id_source("preferences_reduce_motion")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            for (reduce_motion, id) in REDUCE_MOTION_OPTIONS {
+                                if ui
+                                    .selectable_label(
+                                        self.reduce_motion == reduce_motion,
+                                        text(&self.locale, id),
+                                    )
+                                    .clicked()
+                                {
+                                    self.set_reduce_motion(reduce_motion);
+                                }
+                            }
+                        });
+                    ui.end_row();
+                });
+                let mut auto_hide_menu = self.auto_hide_menu;
+                if ui
+                    .checkbox(
+                        &mut auto_hide_menu,
+                        text(&self.locale, "settings-menu-auto-hide-menu"),
+                    )
+                    .clicked()
+                {
+                    self.set_auto_hide_menu(auto_hide_menu);
+                }
+                let mut hide_menu_bar = self.hide_menu_bar;
+                if ui
+                    .checkbox(&mut hide_menu_bar, text(&self.locale, "view-menu-hide-menu-bar"))
+                    .clicked()
+                {
+                    self.set_hide_menu_bar(hide_menu_bar);
+                }
+                let mut start_fullscreen = self.start_fullscreen;
+                if ui
+                    .checkbox(
+                        &mut start_fullscreen,
+                        text(&self.locale, "preferences-start-fullscreen"),
+                    )
+                    .clicked()
+                {
+                    self.set_start_fullscreen(start_fullscreen);
+                }
+                let mut confirm_exit = self.confirm_exit;
+                if ui
+                    .checkbox(
+                        &mut confirm_exit,
+                        text(&self.locale, "settings-menu-confirm-exit"),
+                    )
+                    .clicked()
+                {
+                    self.set_confirm_exit(confirm_exit);
+                }
+                ui.separator();
+
+                ui.heading(text(&self.locale, "preferences-shortcuts"));
+                self.shortcuts_grid(ui);
+                ui.separator();
+
+                #[cfg(feature = "gamepad")]
+                {
+                    ui.heading(text(&self.locale, "preferences-gamepad"));
+                    self.gamepad_grid(ui);
+                    ui.separator();
+                }
+
+                if ui
+                    .button(text(&self.locale, "preferences-reset"))
+                    .clicked()
+                {
+                    self.reset_preferences();
+                }
+            });
+        if !is_open {
+            self.is_preferences_visible = false;
+        }
+    }
+
+    /// Renders a combo box to pick the GUI language, including following the system locale.
+    fn language_combo_box(&mut self, ui: &mut egui::Ui) {
+        let automatic = text(&self.locale, "settings-menu-language-automatic");
+        let selected = match &self.language_override {
+            Some(language) => Cow::Owned(language_name(language)),
+            None => automatic.clone(),
+        };
+        let mut picked = None;
+        ComboBox::from_id_source("preferences_language")
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                if ui
+                    .selectable_label(self.language_override.is_none(), automatic)
+                    .clicked()
+                {
+                    picked = Some(None);
+                }
+                for language in available_languages() {
+                    if ui
+                        .selectable_label(
+                            self.language_override.as_ref() == Some(language),
+                            language_name(language),
+                        )
+                        .clicked()
+                    {
+                        picked = Some(Some(language.clone()));
+                    }
+                }
+            });
+        if let Some(language) = picked {
+            self.set_language(language);
+        }
+    }
+
+    /// Puts every preference back to its default, through the same paths as changing them by hand.
+    fn reset_preferences(&mut self) {
+        let defaults = Preferences::default();
+        self.set_language(None);
+        self.set_check_for_updates(defaults.check_for_updates);
+        self.select_default_quality(defaults.quality);
+        self.default_volume = defaults.volume;
+        self.is_muted = defaults.is_muted;
+        self.send_default_volume();
+        self.set_pause_on_unfocus(defaults.pause_on_unfocus);
+        self.set_forward_mouse_wheel(defaults.forward_mouse_wheel);
+        self.set_auto_hide_menu(defaults.auto_hide_menu);
+        self.set_hide_menu_bar(defaults.hide_menu_bar);
+        self.set_start_fullscreen(defaults.start_fullscreen);
+        self.set_confirm_exit(defaults.confirm_exit);
+        self.set_autoplay_policy(defaults.autoplay);
+        self.select_max_fps(defaults.max_fps);
+        self.set_theme(defaults.theme);
+        self.set_ui_scale(defaults.ui_scale);
+        self.set_reduce_motion(defaults.reduce_motion);
+        self.set_shortcuts(defaults.shortcuts);
+        #[cfg(feature = "gamepad")]
+        self.set_gamepad_mapping(defaults.gamepad_mapping);
+    }
+
+    /// Renders every rebindable shortcut, each of which can be clicked to rebind it with the next key press.
+    fn shortcuts_grid(&mut self, ui: &mut egui::Ui) {
+        Grid::new("preferences_shortcuts").num_columns(2).show(ui, |ui| {
+            for (action, id) in SHORTCUT_ACTIONS {
+                ui.label(text(&self.locale, id));
+                let is_rebinding = self.rebinding_shortcut == Some(action);
+                let label = if is_rebinding {
+                    text(&self.locale, "preferences-shortcut-press-key").into_owned()
+                } else {
+                    ui.ctx().format_shortcut(&self.shortcuts.get(action))
+                };
+                if ui.selectable_label(is_rebinding, label).clicked() {
+                    self.rebinding_shortcut = Some(action);
+                    self.shortcut_conflict = None;
+                }
+                ui.end_row();
+            }
+        });
+        if let Some((shortcut, id)) = self.shortcut_conflict {
+            let args = HashMap::from([
+                ("shortcut", FluentValue::from(ui.ctx().format_shortcut(&shortcut))),
+                ("action", FluentValue::from(text(&self.locale, id).into_owned())),
+            ]);
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                text_with_args(&self.locale, "preferences-shortcut-conflict", &args),
+            );
+        }
+    }
+
+    /// Takes the first key press of this frame for the shortcut being rebound, hiding every key press from the rest of the GUI.
+    /// Escape cancels, and a shortcut that's already bound to another action is refused with a warning.
+    fn capture_shortcut(&mut self, egui_ctx: &egui::Context) {
+        let Some(action) = self.rebinding_shortcut.filter(|_| self.is_preferences_visible) else {
+            self.rebinding_shortcut = None;
+            self.shortcut_conflict = None;
+            return;
+        };
+        let pressed = egui_ctx.input_mut(|input| {
+            let pressed = input.events.iter().find_map(|event| match event {
+                Event::Key {
+                    key,
+                    pressed: true,
+                    modifiers,
+                    ..
+                } => Some((*key, *modifiers)),
+                _ => None,
+            });
+            input.events.retain(|event| !matches!(event, Event::Key { .. }));
+            pressed
+        });
+        let Some((key, modifiers)) = pressed else {
+            return;
+        };
+        if key == Key::Escape {
+            self.rebinding_shortcut = None;
+            self.shortcut_conflict = None;
+            return;
+        }
+        let Some(shortcut) = Shortcuts::captured(modifiers, key) else {
+            return;
+        };
+        // The Edit menu's shortcuts are the platform's own, and the language one is always there for translators.
+        // They can't be rebound, nor be taken by another action. The other fixed shortcuts, on `,`, `[` and `]`,
+        // can't be taken either, as egui has no keys for those to begin with.
+        let conflict = self
+            .shortcuts
+            .conflict(action, shortcut)
+            .and_then(|other| SHORTCUT_ACTIONS.iter().find(|(candidate, _)| *candidate == other))
+            .map(|&(_, id)| id)
+            .or_else(|| {
+                CLIPBOARD_ITEMS
+                    .iter()
+                    .find(|(_, key, _)| KeyboardShortcut::new(Modifiers::COMMAND, *key) == shortcut)
+                    .map(|&(_, _, id)| id)
+            })
+            .or_else(|| {
+                (shortcut == CYCLE_LANGUAGE_SHORTCUT).then_some("preferences-shortcut-cycle-language")
+            });
+        if let Some(id) = conflict {
+            self.shortcut_conflict = Some((shortcut, id));
+            return;
+        }
+        self.rebinding_shortcut = None;
+        self.shortcut_conflict = None;
+        let mut shortcuts = self.shortcuts.clone();
+        shortcuts.set(action, shortcut);
+        self.set_shortcuts(shortcuts);
+    }
+
+    fn set_shortcuts(&mut self, shortcuts: Shortcuts) {
+        self.shortcuts = shortcuts.clone();
+        self.dispatch(RuffleEvent::SetShortcuts(shortcuts));
+    }
+
+    /// Renders the key that each gamepad button presses, which can be changed or unmapped.
+    #[cfg(feature = "gamepad")]
+    fn gamepad_grid(&mut self, ui: &mut egui::Ui) {
+        let mut changed = None;
+        Grid::new("preferences_gamepad").num_columns(2).show(ui, |ui| {
+            for (button, id) in GAMEPAD_BUTTONS {
+                ui.label(text(&self.locale, id));
+                let key = self.gamepad_mapping.get(button);
+                let none = text(&self.locale, "preferences-gamepad-none");
+                ComboBox::from_id_source(id)
+                    .selected_text(key.map_or(none.clone(), |key| Cow::Borrowed(key.name())))
+                    .show_ui(ui, |ui| {
+                        if ui.selectable_label(key.is_none(), none).clicked() {
+                            changed = Some((button, None));
+                        }
+                        for bindable in BINDABLE_KEYS {
+                            if ui
+                                .selectable_label(key == Some(bindable), bindable.name())
+                                .clicked()
+                            {
+                                changed = Some((button, Some(bindable)));
+                            }
+                        }
+                    });
+                ui.end_row();
+            }
+        });
+        if let Some((button, key)) = changed {
+            let mut mapping = self.gamepad_mapping.clone();
+            mapping.set(button, key);
+            self.set_gamepad_mapping(mapping);
+        }
+    }
+
+    #[cfg(feature = "gamepad")]
+    fn set_gamepad_mapping(&mut self, mapping: GamepadMapping) {
+        self.gamepad_mapping = mapping.clone();
+        self.dispatch(RuffleEvent::SetGamepadMapping(mapping));
+    }
+
+    /// Lets the user know why the movie is being reloaded.
+    pub fn watched_file_reloaded(&mut self) {
+        self.push_toast(text(&self.locale, "watched-file-reloaded").into_owned(), ToastLevel::Info);
+    }
+
+    /// Lets the user know that a gamepad was plugged in or out.
+    #[cfg(feature = "gamepad")]
+    pub fn gamepad_connected(&mut self, name: String, is_connected: bool) {
+        let args = HashMap::from([("name", FluentValue::from(name))]);
+        let id = if is_connected {
+            "gamepad-connected"
+        } else {
+            "gamepad-disconnected"
+        };
+        self.push_toast(text_with_args(&self.locale, id, &args).into_owned(), ToastLevel::Info);
+    }
+
+    /// Whether the next key press is going to a shortcut being rebound, rather than the movie or menus.
+    pub fn is_rebinding_shortcut(&self) -> bool {
+        self.rebinding_shortcut.is_some()
+    }
+
+    /// Opens the error dialog with the given message, replacing any error already shown.
+    pub fn show_error(&mut self, message: String) {
+        self.error_message = Some(ErrorMessage {
+            message,
+            detail: None,
+        });
+    }
+
+    /// Shows that the movie at `source`, a path or URL, couldn't be loaded, along with why.
+    pub fn show_load_error(&mut self, source: String, detail: String) {
+        let args = HashMap::from([("source", FluentValue::from(source))]);
+        let message = text_with_args(&self.locale, "error-load-failed", &args).into_owned();
+        self.error_message = Some(ErrorMessage {
+            message,
+            detail: Some(detail),
+        });
+    }
+
+    fn error_window(&mut self, egui_ctx: &egui::Context) {
+        let Some(error) = &self.error_message else {
+            return;
+        };
+        let mut close_dialog = false;
+        egui::Window::new(text(&self.locale, "error-title"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.label(error.message.as_str());
+                    if let Some(detail) = &error.detail {
+                        ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                            ui.label(RichText::new(detail).monospace());
+                        });
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.button(text(&self.locale, "dialog-ok")).clicked() {
+                            close_dialog = true;
+                        }
+                        if ui.button(text(&self.locale, "error-copy")).clicked() {
+                            let copied = match &error.detail {
+                                Some(detail) => format!("{}\n{detail}", error.message),
+                                None => error.message.clone(),
+                            };
+                            ui.output_mut(|output| output.copied_text = copied);
+                        }
+                    });
+                });
+            });
+        if close_dialog {
+            self.error_message = None;
+        }
+    }
+
+    /// Offers to copy a website's address when no browser could be opened for it.
+    fn unopened_url_window(&mut self, egui_ctx: &egui::Context) {
+        let Some(url) = &self.unopened_url else {
+            return;
+        };
+        let mut close_dialog = false;
+        egui::Window::new(text(&self.locale, "browser-failed-title"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.label(text(&self.locale, "browser-failed-message"));
+                    ui.label(RichText::new(url.as_str()).monospace());
+                    ui.horizontal(|ui| {
+                        if ui.button(text(&self.locale, "dialog-ok")).clicked() {
+                            close_dialog = true;
+                        }
+                        if ui.button(text(&self.locale, "browser-failed-copy")).clicked() {
+                            ui.output_mut(|output| output.copied_text = url.clone());
+                        }
+                    });
+                });
+            });
+        if close_dialog {
+            self.unopened_url = None;
+        }
+    }
+
+    /// Looks for a newer release in the background. Automatic checks stay out of sight unless they find one.
+    pub fn check_for_updates(&mut self, is_automatic: bool) {
+        if matches!(self.update_check, Some(UpdateCheck::Checking)) {
+            // Already checking, but now the user wants to see the outcome.
+            self.is_update_check_automatic &= is_automatic;
+            return;
+        }
+        self.update_check = Some(UpdateCheck::Checking);
+        self.is_update_check_automatic = is_automatic;
+        let event_loop = self.event_loop.clone();
+        update::check_for_updates(move |event| {
+            let _ = event_loop.send_event(event);
+        });
+    }
+
+    pub fn update_check_finished(&mut self, result: anyhow::Result<Option<Release>>) {
+        let is_newer_release = matches!(result, Ok(Some(_)));
+        self.update_check = if self.is_update_check_automatic && !is_newer_release {
+            None
+        } else {
+            Some(UpdateCheck::Finished(result))
+        };
+    }
+
+    fn update_check_window(&mut self, egui_ctx: &egui::Context) {
+        let Some(update_check) = &self.update_check else {
+            return;
+        };
+        if self.is_update_check_automatic && matches!(update_check, UpdateCheck::Checking) {
+            return;
+        }
+        let mut close_dialog = false;
+        let mut download_url = None;
+        egui::Window::new(text(&self.locale, "update-check-title"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    match update_check {
+                        UpdateCheck::Checking => {
+                            ui.horizontal(|ui| {
+                                ui.spinner();
+                                ui.label(text(&self.locale, "update-check-checking"));
+                            });
+                        }
+                        UpdateCheck::Finished(Ok(Some(release))) => {
+                            let args =
+                                HashMap::from([("version", FluentValue::from(release.name.clone()))]);
+                            ui.label(text_with_args(&self.locale, "update-check-available", &args));
+                        }
+                        UpdateCheck::Finished(Ok(None)) => {
+                            ui.label(text(&self.locale, "update-check-up-to-date"));
+                        }
+                        UpdateCheck::Finished(Err(_)) => {
+                            ui.label(text(&self.locale, "update-check-failed"));
+                        }
+                    }
+                    ui.horizontal(|ui| {
+                        if let UpdateCheck::Finished(Ok(Some(release))) = update_check {
+                            if ui.button(text(&self.locale, "update-check-download")).clicked() {
+                                download_url = Some(release.url.clone());
+                            }
+                        }
+                        if ui.button(text(&self.locale, "dialog-ok")).clicked() {
+                            close_dialog = true;
+                        }
+                    });
+                });
+            });
+        if let Some(url) = download_url {
+            self.open_website(&url);
+            close_dialog = true;
+        }
+        if close_dialog {
+            // Closing while still checking leaves the outcome to be shown like an automatic check's.
+            self.is_update_check_automatic = true;
+            if !matches!(self.update_check, Some(UpdateCheck::Checking)) {
+                self.update_check = None;
+            }
+        }
+    }
+
+    fn about_window(&mut self, egui_ctx: &egui::Context) {
+        // A copy, so that the window's contents can use `self` while the window holds on to it.
+        let mut is_open = self.is_about_visible;
+        egui::Window::new(text(&self.locale, "about-ruffle"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .open(&mut is_open)
+            .show(egui_ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.label(
+                        RichText::new("Ruffle")
+                            .color(Color32::from_rgb(0xFF, 0xAD, 0x33))
+                            .size(32.0),
+                    );
+                    Grid::new("about_ruffle_version_info")
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label(text(&self.locale, "about-ruffle-version"));
+                            ui.label(env!("CARGO_PKG_VERSION"));
+                            ui.end_row();
+
+                            ui.label(text(&self.locale, "about-ruffle-channel"));
+                            ui.label(env!("CFG_RELEASE_CHANNEL"));
+                            ui.end_row();
+
+                            ui.label(text(&self.locale, "about-ruffle-build-time"));
+                            ui.label(format_timestamp(env!("VERGEN_BUILD_TIMESTAMP")));
+                            ui.end_row();
+
+                            ui.label(text(&self.locale, "about-ruffle-commit-ref"));
+                            ui.hyperlink_to(
+                                env!("VERGEN_GIT_SHA"),
+                                format!(
+                                    "https://github.com/ruffle-rs/ruffle/commit/{}",
+                                    env!("VERGEN_GIT_SHA")
+                                ),
+                            );
+                            ui.end_row();
+
+                            ui.label(text(&self.locale, "about-ruffle-commit-time"));
+                            ui.label(format_timestamp(env!("VERGEN_GIT_COMMIT_TIMESTAMP")));
+                            ui.end_row();
+
+                            ui.label(text(&self.locale, "about-ruffle-build-features"));
+                            ui.horizontal_wrapped(|ui| {
+                                ui.label(env!("VERGEN_CARGO_FEATURES").replace(',', ", "));
+                            });
+                            ui.end_row();
+
+                            ui.label("");
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .button(text(&self.locale, "about-ruffle-copy-version-info"))
+                                    .clicked()
+                                {
+                                    let info = version_info(&self.locale);
+                                    ui.output_mut(|o| o.copied_text = info);
+                                    self.version_info_copied_at = Some(Instant::now());
+                                }
+                                if let Some(copied_at) = self.version_info_copied_at {
+                                    let elapsed = copied_at.elapsed();
+                                    if elapsed < COPIED_CONFIRMATION_DURATION {
+                                        ui.label(text(&self.locale, "about-ruffle-copied"));
+                                        ui.ctx().request_repaint_after(
+                                            COPIED_CONFIRMATION_DURATION - elapsed,
+                                        );
+                                    } else {
+                                        self.version_info_copied_at = None;
+                                    }
+                                }
+                            });
+                            ui.end_row();
+                        });
+
+                    ui.horizontal(|ui| {
+                        ui.hyperlink_to(
+                            text(&self.locale, "about-ruffle-visit-website"),
+                            "https://ruffle.rs",
+                        );
+                        ui.hyperlink_to(
+                            text(&self.locale, "about-ruffle-visit-github"),
+                            "https://github.com/ruffle-rs/ruffle/",
+                        );
+                        ui.hyperlink_to(
+                            text(&self.locale, "about-ruffle-visit-discord"),
+                            "https://discord.gg/ruffle",
+                        );
+                        ui.hyperlink_to(
+                            text(&self.locale, "about-ruffle-visit-sponsor"),
+                            "https://opencollective.com/ruffle/",
+                        );
+                        ui.shrink_width_to_current();
+                    });
+                })
+            });
+        self.is_about_visible = is_open;
+    }
+
+    /// Renders the right-click context menu at the pointer, with the items provided by the movie followed by
+    /// Ruffle's own items.
+    fn context_menu(&mut self, egui_ctx: &egui::Context, is_fullscreen: bool) {
+        // The press that opened the menu is only seen by egui on the frame it first shows,
+        // so it mustn't count as a click elsewhere.
+        let is_first_frame = self.context_menu_position.is_none();
+        let position = *self.context_menu_position.get_or_insert_with(|| {
+            egui_ctx
+                .input(|input| input.pointer.latest_pos())
+                .unwrap_or_else(|| egui_ctx.screen_rect().center())
+        });
+
+        let area = Area::new("context_menu")
+            .order(Order::Foreground)
+            .fixed_pos(position)
+            .constrain(true)
+            .show(egui_ctx, |ui| {
+                // Look like egui's own menus.
+                let style = ui.style_mut();
+                style.spacing.button_padding = vec2(2.0, 0.0);
+                style.visuals.widgets.active.bg_stroke = Stroke::NONE;
+                style.visuals.widgets.hovered.bg_stroke = Stroke::NONE;
+                style.visuals.widgets.inactive.weak_bg_fill = Color32::TRANSPARENT;
+                style.visuals.widgets.inactive.bg_stroke = Stroke::NONE;
+
+                Frame::menu(ui.style())
+                    .show(ui, |ui| {
+                        ui.set_max_width(150.0);
+                        ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                            self.context_menu_items(ui, is_fullscreen)
+                        })
+                        .inner
+                    })
+                    .inner
+            });
+
+        // Submenus are areas of their own, so pressing inside one doesn't count as elsewhere.
+        let pressed_at = egui_ctx.input(|input| {
+            input
+                .pointer
+                .any_pressed()
+                .then(|| input.pointer.interact_pos())
+                .flatten()
+        });
+        let clicked_elsewhere = !is_first_frame
+            && pressed_at.is_some_and(|pos| {
+                !area.response.rect.contains(pos)
+                    && egui_ctx
+                        .layer_id_at(pos)
+                        .map_or(true, |layer| layer.order != Order::Foreground)
+            });
+
+        if area.inner
+            || clicked_elsewhere
+            || egui_ctx.input_mut(|input| input.consume_key(Modifiers::NONE, Key::Escape))
+        {
+            // Hide menu.
+            self.context_menu.clear();
+            self.is_context_menu_visible = false;
+            self.context_menu_position = None;
+        }
+    }
+
+    /// Renders the items of the context menu, returning whether one of them was clicked.
+    fn context_menu_items(&mut self, ui: &mut egui::Ui, is_fullscreen: bool) -> bool {
+        let mut item_clicked = false;
+        for (i, item) in self.context_menu.iter().enumerate() {
+            if i != 0 && item.separator_before {
+                ui.separator();
+            }
+            let clicked = if item.checked {
+                ui.add_enabled(item.enabled, Checkbox::new(&mut true, &item.caption))
+                    .clicked()
+            } else {
+                ui.add_enabled(item.enabled, Button::new(&item.caption))
+                    .clicked()
+            };
+            if clicked {
+                self.dispatch(RuffleEvent::ContextMenuItemClicked(i));
+                item_clicked = true;
+            }
+        }
+        if !self.context_menu.is_empty() {
+            ui.separator();
+        }
+        if self.can_copy_frame
+            && ui
+                .button(text(&self.locale, "context-menu-copy-image"))
+                .clicked()
+        {
+            self.dispatch(RuffleEvent::CopyFrame);
+            item_clicked = true;
+        }
+        if ui
+            .button(text(&self.locale, "context-menu-about"))
+            .clicked()
+        {
+            self.show_about_screen(ui);
+            item_clicked = true;
+        }
+        menu::menu_button(ui, text(&self.locale, "view-menu-quality"), |ui| {
+            self.quality_menu(ui);
+        });
+        let mut fullscreen = is_fullscreen;
+        if Checkbox::new(&mut fullscreen, text(&self.locale, "view-menu-fullscreen"))
+            .ui(ui)
+            .clicked()
+        {
+            self.toggle_fullscreen();
+            item_clicked = true;
+        }
+        item_clicked
+    }
+
+    fn open_file(&mut self, ui: &mut egui::Ui) {
+        self.dispatch(RuffleEvent::OpenFileWithDialog);
+        ui.close_menu();
+    }
+
+    fn open_file_in_new_window(&mut self, ui: &mut egui::Ui) {
+        self.dispatch(RuffleEvent::OpenFileInNewWindowWithDialog);
+        ui.close_menu();
+    }
+
+    /// Renders the contents of the "Open Recent" submenu.
+    fn recent_files_menu(&mut self, ui: &mut egui::Ui) {
+        let mut clicked = None;
+        for path in &self.recent_files {
+            let mut label = RichText::new(path.display().to_string());
+            if !path.exists() {
+                // Still clickable, so that the user gets told why it can't be opened. It's dropped from the list then.
+                label = label.weak();
+            }
+            if Button::new(label).ui(ui).clicked() {
+                clicked = Some(path.clone());
+            }
+        }
+        if let Some(path) = clicked {
+            self.dispatch(RuffleEvent::OpenRecent(path));
+            ui.close_menu();
+        }
+
+        ui.separator();
+
+        if Button::new(text(&self.locale, "file-menu-clear-recent"))
+            .ui(ui)
+            .clicked()
+        {
+            self.recent_files.clear();
+            self.dispatch(RuffleEvent::SetRecentFiles(vec![]));
+            ui.close_menu();
+        }
+    }
+
+    fn go_to_frame_window(&mut self, egui_ctx: &egui::Context) {
+        let Some(frame_text) = &mut self.go_to_frame_text else {
+            return;
+        };
+        let num_frames = self
+            .movie_metadata
+            .as_ref()
+            .map_or(0, |metadata| u32::from(metadata.num_frames));
+        let mut submit = false;
+        let mut cancel = false;
+        egui::Window::new(text(&self.locale, "go-to-frame"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    let (enter_pressed, esc_pressed) = ui.ctx().input_mut(|input| {
+                        (
+                            input.consume_key(Modifiers::NONE, Key::Enter),
+                            input.consume_key(Modifiers::NONE, Key::Escape),
+                        )
+                    });
+                    let args = HashMap::from([("count", FluentValue::from(num_frames))]);
+                    let response = TextEdit::singleline(frame_text)
+                        .hint_text(text_with_args(&self.locale, "go-to-frame-hint", &args))
+                        .ui(ui);
+                    if response.changed() {
+                        self.go_to_frame_error = None;
+                    }
+                    if let Some(error) = &self.go_to_frame_error {
+                        ui.colored_label(Color32::RED, error);
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.button(text(&self.locale, "dialog-ok")).clicked() || enter_pressed {
+                            submit = true;
+                        }
+                        if ui.button(text(&self.locale, "dialog-cancel")).clicked() || esc_pressed {
+                            cancel = true;
+                        }
+                    });
+                });
+            });
+        if submit {
+            match frame_text.trim().parse::<u32>() {
+                Ok(frame) if (1..=num_frames).contains(&frame) => {
+                    self.go_to_frame_text = None;
+                    self.dispatch(RuffleEvent::SeekToFrame(frame));
+                }
+                _ => {
+                    let args = HashMap::from([("count", FluentValue::from(num_frames))]);
+                    self.go_to_frame_error =
+                        Some(text_with_args(&self.locale, "go-to-frame-invalid", &args).into_owned());
+                }
+            }
+        } else if cancel {
+            self.go_to_frame_text = None;
+        }
+    }
+
+    /// Asks how many frames to record and where to save them.
+    fn record_frames_window(&mut self, egui_ctx: &egui::Context) {
+        let Some(count) = &mut self.record_frames_count else {
+            return;
+        };
+        let mut browse = false;
+        let mut start = false;
+        let mut cancel = false;
+        egui::Window::new(text(&self.locale, "record-frames"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_ctx, |ui| {
+                Grid::new("record_frames").num_columns(2).show(ui, |ui| {
+                    ui.label(text(&self.locale, "record-frames-count"));
+                    ui.add(DragValue::new(count).clamp_range(1..=MAX_RECORDED_FRAMES));
+                    ui.end_row();
+
+                    ui.label(text(&self.locale, "record-frames-folder"));
+                    ui.horizontal(|ui| {
+                        match &self.record_frames_dir {
+                            Some(dir) => ui.label(dir.display().to_string()),
+                            None => ui.weak(text(&self.locale, "record-frames-no-folder")),
+                        };
+                        if ui.button(text(&self.locale, "record-frames-browse")).clicked() {
+                            browse = true;
+                        }
+                    });
+                    ui.end_row();
+                });
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(
+                            self.record_frames_dir.is_some(),
+                            Button::new(text(&self.locale, "record-frames-start")),
+                        )
+                        .clicked()
+                    {
+                        start = true;
+                    }
+                    if ui.button(text(&self.locale, "dialog-cancel")).clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+        let count = *count;
+        if browse {
+            // The dialog blocks, so it's left to the event loop instead of holding up the GUI.
+            self.dispatch(RuffleEvent::PickRecordingDir);
+        }
+        if start {
+            if let Some(dir) = self.record_frames_dir.clone() {
+                self.record_frames_count = None;
+                self.dispatch(RuffleEvent::RecordFrames { count, dir });
+            }
+        } else if cancel {
+            self.record_frames_count = None;
+        }
+    }
+
+    pub fn set_recording_dir(&mut self, dir: PathBuf) {
+        self.record_frames_dir = Some(dir);
+    }
+
+    /// Shows how many frames have been recorded so far, with a button to stop early.
+    fn recording_window(&mut self, egui_ctx: &egui::Context) {
+        let Some((saved, count)) = self.recording_progress else {
+            return;
+        };
+        let mut cancel = false;
+        egui::Window::new(text(&self.locale, "record-frames"))
+            .id(Id::new("recording_progress"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    let args = HashMap::from([
+                        ("saved", FluentValue::from(saved)),
+                        ("count", FluentValue::from(count)),
+                    ]);
+                    ui.add(
+                        ProgressBar::new(saved as f32 / count as f32)
+                            .text(text_with_args(&self.locale, "record-frames-progress", &args)),
+                    );
+                    if ui.button(text(&self.locale, "dialog-cancel")).clicked() {
+                        cancel = true;
+                    }
+                });
+            });
+        if cancel {
+            self.dispatch(RuffleEvent::CancelRecording);
+        }
+    }
+
+    pub fn set_recording_progress(&mut self, progress: Option<(u32, u32)>) {
+        self.recording_progress = progress;
+    }
+
+    /// Reports how many frames were recorded, and where, once recording is over.
+    pub fn recording_finished(&mut self, result: anyhow::Result<(u32, PathBuf)>) {
+        let level = ToastLevel::of(&result);
+        let message = match result {
+            Ok((count, dir)) => {
+                let args = HashMap::from([
+                    ("count", FluentValue::from(count)),
+                    ("path", FluentValue::from(dir.display().to_string())),
+                ]);
+                text_with_args(&self.locale, "record-frames-done", &args)
+            }
+            Err(e) => {
+                tracing::error!("Couldn't record frames: {e}");
+                let args = HashMap::from([("error", FluentValue::from(e.to_string()))]);
+                text_with_args(&self.locale, "record-frames-failed", &args)
+            }
+        };
+        self.push_toast(message.into_owned(), level);
+    }
+
+    /// Renders the contents of the "Bookmarks" menu.
+    fn bookmarks_menu(&mut self, ui: &mut egui::Ui) {
+        if ui
+            .add_enabled(
+                self.movie_url.is_some(),
+                Button::new(text(&self.locale, "bookmarks-menu-add")),
+            )
+            .clicked()
+        {
+            self.show_add_bookmark_dialog();
+            ui.close_menu();
+        }
+        if ui
+            .add_enabled(
+                !self.bookmarks.is_empty(),
+                Button::new(text(&self.locale, "bookmarks-menu-manage")),
+            )
+            .clicked()
+        {
+            self.is_bookmarks_manager_visible = true;
+            ui.close_menu();
+        }
+        if !self.bookmarks.is_empty() {
+            ui.separator();
+        }
+        let mut clicked = None;
+        for bookmark in &self.bookmarks {
+            if ui
+                .button(&bookmark.name)
+                .on_hover_text(&bookmark.url)
+                .clicked()
+            {
+                clicked = Some(bookmark.url.clone());
+            }
+        }
+        if let Some(url) = clicked {
+            match Url::parse(&url) {
+                Ok(url) => {
+                    self.dispatch(RuffleEvent::OpenURL(url, vec![]));
+                }
+                Err(e) => {
+                    let args = HashMap::from([
+                        ("url", FluentValue::from(url)),
+                        ("error", FluentValue::from(e.to_string())),
+                    ]);
+                    let message = text_with_args(&self.locale, "bookmark-invalid", &args);
+                    self.show_error(message.into_owned());
+                }
+            }
+            ui.close_menu();
+        }
+    }
+
+    /// Warnings are about a single movie, so they're cleared whenever another one is loaded.
+    pub fn movie_url(&self) -> Option<&Url> {
+        self.movie_url.as_ref()
+    }
+
+    pub fn set_movie_url(&mut self, url: Option<Url>) {
+        // Opening anything other than the entry being played leaves the playlist.
+        if let Some(index) = self.playlist_position {
+            if self.playlist.get(index) != url.as_ref() {
+                self.set_playlist_position(None);
+            }
+        }
+        self.movie_url = url;
+        self.warnings.clear();
+        self.are_warnings_expanded = false;
+    }
+
+    /// Opens the dialog to name a bookmark for the current movie,
+    /// suggesting its current name if it's already bookmarked.
+    fn show_add_bookmark_dialog(&mut self) {
+        let Some(url) = &self.movie_url else {
+            return;
+        };
+        let name = match self.bookmarks.iter().find(|bookmark| bookmark.url == url.as_str()) {
+            Some(bookmark) => bookmark.name.clone(),
+            None => url_name(url).to_string(),
+        };
+        self.new_bookmark_name = Some(name);
+    }
+
+    fn add_bookmark_window(&mut self, egui_ctx: &egui::Context) {
+        let Some(name) = &mut self.new_bookmark_name else {
+            return;
+        };
+        let mut save = false;
+        let mut cancel = false;
+        egui::Window::new(text(&self.locale, "bookmark-add"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    let (enter_pressed, esc_pressed) = ui.ctx().input_mut(|input| {
+                        (
+                            input.consume_key(Modifiers::NONE, Key::Enter),
+                            input.consume_key(Modifiers::NONE, Key::Escape),
+                        )
+                    });
+                    TextEdit::singleline(name)
+                        .hint_text(text(&self.locale, "bookmark-name-hint"))
+                        .ui(ui);
+                    let is_valid = !name.trim().is_empty();
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(is_valid, Button::new(text(&self.locale, "dialog-ok")))
+                            .clicked()
+                            || (enter_pressed && is_valid)
+                        {
+                            save = true;
+                        }
+                        if ui.button(text(&self.locale, "dialog-cancel")).clicked() || esc_pressed {
+                            cancel = true;
+                        }
+                    });
+                });
+            });
+        if save {
+            if let Some(name) = self.new_bookmark_name.take() {
+                self.add_bookmark(name.trim().to_string());
+            }
+        } else if cancel {
+            self.new_bookmark_name = None;
+        }
+    }
+
+    /// Bookmarks the current movie, renaming its bookmark instead if it already has one.
+    fn add_bookmark(&mut self, name: String) {
+        let Some(url) = &self.movie_url else {
+            return;
+        };
+        let url = url.to_string();
+        match self.bookmarks.iter_mut().find(|bookmark| bookmark.url == url) {
+            Some(bookmark) => bookmark.name = name,
+            None => self.bookmarks.push(Bookmark { name, url }),
+        }
+        self.save_bookmarks();
+    }
+
+    fn save_bookmarks(&self) {
+        self.dispatch(RuffleEvent::SetBookmarks(self.bookmarks.clone()));
+    }
+
+    fn bookmarks_manager_window(&mut self, egui_ctx: &egui::Context) {
+        if !self.is_bookmarks_manager_visible {
+            return;
+        }
+        let mut is_open = true;
+        let mut changed = false;
+        let mut swap = None;
+        let mut remove = None;
+        egui::Window::new(text(&self.locale, "bookmarks-manage"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .open(&mut is_open)
+            .show(egui_ctx, |ui| {
+                if self.bookmarks.is_empty() {
+                    ui.label(text(&self.locale, "bookmarks-empty"));
+                    return;
+                }
+                let count = self.bookmarks.len();
+                Grid::new("bookmarks").num_columns(4).show(ui, |ui| {
+                    for (i, bookmark) in self.bookmarks.iter_mut().enumerate() {
+                        let response = ui.text_edit_singleline(&mut bookmark.name);
+                        // Names are saved once editing is done, rather than on every keystroke.
+                        if response.lost_focus() {
+                            if bookmark.name.trim().is_empty() {
+                                bookmark.name = bookmark.url.clone();
+                            }
+                            changed = true;
+                        }
+                        response.on_hover_text(bookmark.url.as_str());
+                        if ui
+                            .add_enabled(i > 0, Button::new("⏶"))
+                            .on_hover_text(text(&self.locale, "bookmarks-move-up"))
+                            .clicked()
+                        {
+                            swap = Some((i - 1, i));
+                        }
+                        if ui
+                            .add_enabled(i + 1 < count, Button::new("⏷"))
+                            .on_hover_text(text(&self.locale, "bookmarks-move-down"))
+                            .clicked()
+                        {
+                            swap = Some((i, i + 1));
+                        }
+                        if ui
+                            .button("🗑")
+                            .on_hover_text(text(&self.locale, "bookmarks-delete"))
+                            .clicked()
+                        {
+                            remove = Some(i);
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+        if let Some((a, b)) = swap {
+            self.bookmarks.swap(a, b);
+            changed = true;
+        }
+        if let Some(i) = remove {
+            self.bookmarks.remove(i);
+            changed = true;
+        }
+        if changed {
+            self.save_bookmarks();
+        }
+        if !is_open {
+            self.is_bookmarks_manager_visible = false;
+        }
+    }
+
+    fn playlist_window(&mut self, egui_ctx: &egui::Context) {
+        if !self.is_playlist_visible {
+            return;
+        }
+        let mut is_open = true;
+        let mut add_files = false;
+        let mut add_current = false;
+        let mut clear = false;
+        let mut play = None;
+        let mut swap = None;
+        let mut remove = None;
+        egui::Window::new(text(&self.locale, "playlist"))
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut is_open)
+            .show(egui_ctx, |ui| {
+                ui.horizontal(|ui| {
+                    add_files = ui.button(text(&self.locale, "playlist-add-files")).clicked();
+                    add_current = ui
+                        .add_enabled(
+                            self.movie_url.is_some(),
+                            Button::new(text(&self.locale, "playlist-add-current")),
+                        )
+                        .clicked();
+                    clear = ui
+                        .add_enabled(
+                            !self.playlist.is_empty(),
+                            Button::new(text(&self.locale, "playlist-clear")),
+                        )
+                        .clicked();
+                });
+                let label = text(&self.locale, "playlist-auto-advance");
+                ui.checkbox(&mut self.auto_advance, label);
+                let label = text(&self.locale, "playlist-loop");
+                ui.checkbox(&mut self.loop_playlist, label);
+                ui.separator();
+                if self.playlist.is_empty() {
+                    ui.label(text(&self.locale, "playlist-empty"));
+                    return;
+                }
+                let count = self.playlist.len();
+                Grid::new("playlist").num_columns(4).show(ui, |ui| {
+                    for (i, url) in self.playlist.iter().enumerate() {
+                        if ui
+                            .selectable_label(self.playlist_position == Some(i), url_name(url))
+                            .on_hover_text(url.as_str())
+                            .clicked()
+                        {
+                            play = Some(i);
+                        }
+                        if ui
+                            .add_enabled(i > 0, Button::new("⏶"))
+                            .on_hover_text(text(&self.locale, "playlist-move-up"))
+                            .clicked()
+                        {
+                            swap = Some((i - 1, i));
+                        }
+                        if ui
+                            .add_enabled(i + 1 < count, Button::new("⏷"))
+                            .on_hover_text(text(&self.locale, "playlist-move-down"))
+                            .clicked()
+                        {
+                            swap = Some((i, i + 1));
+                        }
+                        if ui
+                            .button("🗑")
+                            .on_hover_text(text(&self.locale, "playlist-remove"))
+                            .clicked()
+                        {
+                            remove = Some(i);
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+        if add_files {
+            self.dispatch(RuffleEvent::AddToPlaylistWithDialog);
+        }
+        if add_current {
+            if let Some(url) = self.movie_url.clone() {
+                self.playlist.push(url);
+                // The movie that's playing is the entry that was just added, unless it's already from the playlist.
+                let position = self.playlist_position.or(Some(self.playlist.len() - 1));
+                self.set_playlist_position(position);
+            }
+        }
+        if clear {
+            self.playlist.clear();
+            self.set_playlist_position(None);
+        }
+        if let Some((a, b)) = swap {
+            self.playlist.swap(a, b);
+            let position = self.playlist_position.map(|index| match index {
+                _ if index == a => b,
+                _ if index == b => a,
+                _ => index,
+            });
+            self.set_playlist_position(position);
+        }
+        if let Some(i) = remove {
+            self.playlist.remove(i);
+            let position = match self.playlist_position {
+                Some(index) if index == i => None,
+                Some(index) if index > i => Some(index - 1),
+                position => position,
+            };
+            self.set_playlist_position(position);
+        }
+        if let Some(i) = play {
+            self.play_playlist_entry(i);
+        }
+        if !is_open {
+            self.is_playlist_visible = false;
+        }
+    }
+
+    /// Queues movies at the end of the playlist.
+    pub fn add_to_playlist(&mut self, urls: Vec<Url>) {
+        self.playlist.extend(urls);
+        self.set_playlist_position(self.playlist_position);
+    }
+
+    /// Also lets the window title know where in the playlist the current movie is, as that changes.
+    fn set_playlist_position(&mut self, position: Option<usize>) {
+        self.playlist_position = position;
+        let count = self.playlist.len();
+        self.dispatch(RuffleEvent::PlaylistPositionChanged(
+            position.map(|index| (index + 1, count)),
+        ));
+    }
+
+    fn play_playlist_entry(&mut self, index: usize) {
+        let Some(url) = self.playlist.get(index) else {
+            return;
+        };
+        self.dispatch(RuffleEvent::OpenURL(url.clone(), vec![]));
+        self.set_playlist_position(Some(index));
+    }
+
+    /// The entry after the one that's playing, going back to the first one after the last if the playlist loops.
+    /// Starts from the first entry if the current movie isn't from the playlist.
+    fn next_playlist_index(&self) -> Option<usize> {
+        let count = self.playlist.len();
+        match self.playlist_position {
+            _ if count == 0 => None,
+            None => Some(0),
+            Some(index) if index + 1 < count => Some(index + 1),
+            Some(_) => self.loop_playlist.then_some(0),
+        }
+    }
+
+    /// The entry before the one that's playing, going back to the last one before the first if the playlist loops.
+    fn previous_playlist_index(&self) -> Option<usize> {
+        match self.playlist_position? {
+            0 => self.loop_playlist.then(|| self.playlist.len() - 1),
+            index => Some(index - 1),
+        }
+    }
+
+    /// Plays the next entry of the playlist, if the movie that ended came from it and auto-advance is on.
+    pub fn movie_playback_ended(&mut self) {
+        if !self.auto_advance || self.playlist_position.is_none() {
+            return;
+        }
+        if let Some(index) = self.next_playlist_index() {
+            self.play_playlist_entry(index);
+        }
+    }
+
+    /// Moves `path` to the top of the recent files list, adding it if it's not already there.
+    pub fn add_recent_file(&mut self, path: PathBuf) {
+        self.recent_files.retain(|recent| recent != &path);
+        self.recent_files.insert(0, path);
+        self.recent_files.truncate(MAX_RECENT_FILES);
+        self.dispatch(RuffleEvent::SetRecentFiles(self.recent_files.clone()));
+    }
+
+    /// Takes `path` off the recent files list, such as when it's been found to no longer exist.
+    pub fn remove_recent_file(&mut self, path: &Path) {
+        let len = self.recent_files.len();
+        self.recent_files.retain(|recent| recent != path);
+        if self.recent_files.len() != len {
+            self.dispatch(RuffleEvent::SetRecentFiles(self.recent_files.clone()));
+        }
+    }
+
+    /// Renders the contents of the "Quality" submenu.
+    fn quality_menu(&mut self, ui: &mut egui::Ui) {
+        for (quality, id) in QUALITY_OPTIONS {
+            if ui
+                .radio(self.quality == quality, text(&self.locale, id))
+                .clicked()
+            {
+                self.select_quality(quality);
+                ui.close_menu();
+            }
+        }
+    }
+
+    /// Sets the quality shown as selected, without requesting a change.
+    pub fn set_quality(&mut self, quality: StageQuality) {
+        self.quality = quality;
+    }
+
+    /// Renders the contents of the "Letterbox" submenu.
+    fn letterbox_menu(&mut self, ui: &mut egui::Ui) {
+        let letterboxes = [
+            (Letterbox::On, "view-menu-letterbox-on"),
+            (Letterbox::Off, "view-menu-letterbox-off"),
+            (Letterbox::Fullscreen, "view-menu-letterbox-fullscreen"),
+        ];
+        for (letterbox, id) in letterboxes {
+            let mut checked = self.letterbox == letterbox;
+            if Checkbox::new(&mut checked, text(&self.locale, id))
+                .ui(ui)
+                .clicked()
+            {
+                self.letterbox = letterbox;
+                self.dispatch(RuffleEvent::SetLetterbox(letterbox));
+                ui.close_menu();
+            }
+        }
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui.color_edit_button_srgba(&mut self.letterbox_color).changed() {
+                self.dispatch(RuffleEvent::SetLetterboxColor(self.letterbox_color));
+            }
+            ui.label(text(&self.locale, "view-menu-letterbox-color"));
+        });
+    }
+
+    /// The items of the "File" menu, in the order they're shown in.
+    fn file_menu_items(&self, has_movie: bool) -> [MenuItem; 8] {
+        [
+            MenuItem::new("file-menu-open-file", Self::open_file)
+                .shortcut(self.shortcuts.get(ShortcutAction::OpenFile)),
+            MenuItem::new("file-menu-open-file-new-window", Self::open_file_in_new_window),
+            MenuItem::new("file-menu-open-url", Self::show_open_url_prompt),
+            // Movies read from standard input have nowhere to be read from again.
+            MenuItem::new("file-menu-reload", Self::reload_movie)
+                .shortcut(self.shortcuts.get(ShortcutAction::Reload))
+                .enabled(has_movie && self.movie_url.is_some()),
+            MenuItem::new("file-menu-copy-location", Self::copy_location)
+                .enabled(has_movie && self.movie_url.is_some()),
+            MenuItem::new("file-menu-close", Self::close_movie).enabled(has_movie),
+            MenuItem::new("file-menu-take-screenshot", Self::take_screenshot)
+                .shortcut(self.shortcuts.get(ShortcutAction::TakeScreenshot))
+                .enabled(has_movie),
+            MenuItem::new("file-menu-exit", Self::request_exit)
+                .shortcut(self.shortcuts.get(ShortcutAction::Quit)),
+        ]
+    }
+
+    /// Renders a menu item as a button with its shortcut next to it, and triggers it when clicked.
+    fn menu_item(&mut self, ui: &mut egui::Ui, item: &MenuItem) {
+        let mut button = Button::new(text(&self.locale, item.text_id));
+        if let Some(shortcut) = &item.shortcut {
+            button = button.shortcut_text(ui.ctx().format_shortcut(shortcut));
+        }
+        if ui.add_enabled(item.enabled, button).clicked() {
+            (item.action)(self, ui);
+        }
+    }
+
+    /// Triggers a menu item if its shortcut was pressed.
+    /// Shortcuts work while their menu is closed, so this has to be called every frame.
+    fn menu_item_shortcut(&mut self, ui: &mut egui::Ui, item: &MenuItem) {
+        let Some(shortcut) = &item.shortcut else {
+            return;
+        };
+        if item.enabled && ui.ctx().input_mut(|input| input.consume_shortcut(shortcut)) {
+            (item.action)(self, ui);
+        }
+    }
+
+    /// The zoom items at the top of the "Zoom" submenu, in the order they're shown in.
+    fn zoom_menu_items(&self) -> [MenuItem; 3] {
+        [
+            MenuItem::new("view-menu-zoom-in", |gui, _| gui.zoom_in())
+                .shortcut(self.shortcuts.get(ShortcutAction::ZoomIn)),
+            MenuItem::new("view-menu-zoom-out", |gui, _| gui.zoom_out())
+                .shortcut(self.shortcuts.get(ShortcutAction::ZoomOut)),
+            MenuItem::new("view-menu-zoom-reset", |gui, _| gui.set_zoom(1.0))
+                .shortcut(self.shortcuts.get(ShortcutAction::ZoomReset)),
+        ]
+    }
+
+    /// Renders the contents of the "Zoom" submenu.
+    fn zoom_menu(&mut self, ui: &mut egui::Ui) {
+        for item in &self.zoom_menu_items() {
+            self.menu_item(ui, item);
+        }
+        ui.separator();
+        for zoom in ZOOM_LEVELS {
+            let mut checked = self.zoom == zoom;
+            if Checkbox::new(&mut checked, format!("{}%", zoom * 100.0))
+                .ui(ui)
+                .clicked()
+            {
+                self.set_zoom(zoom);
+                ui.close_menu();
+            }
+        }
+        ui.separator();
+        let mut percent = self.zoom * 100.0;
+        let range = *ZOOM_RANGE.start() * 100.0..=*ZOOM_RANGE.end() * 100.0;
+        if ui
+            .add(
+                Slider::new(&mut percent, range)
+                    .suffix("%")
+                    .text(text(&self.locale, "view-menu-zoom-custom")),
+            )
+            .changed()
+        {
+            self.set_zoom(percent / 100.0);
+        }
+    }
+
+    /// Zooms in to the next zoom level above the current one.
+    fn zoom_in(&mut self) {
+        if let Some(&zoom) = ZOOM_LEVELS.iter().find(|&&zoom| zoom > self.zoom) {
+            self.set_zoom(zoom);
+        }
+    }
+
+    /// Zooms out to the next zoom level below the current one.
+    fn zoom_out(&mut self) {
+        if let Some(&zoom) = ZOOM_LEVELS.iter().rev().find(|&&zoom| zoom < self.zoom) {
+            self.set_zoom(zoom);
+        }
+    }
+
+    fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom.clamp(*ZOOM_RANGE.start(), *ZOOM_RANGE.end());
+        self.dispatch(RuffleEvent::SetZoom(self.zoom));
+    }
+
+    /// Renders the contents of the "Scale Mode" submenu.
+    fn scale_mode_menu(&mut self, ui: &mut egui::Ui) {
+        let scale_modes = [
+            (ScaleMode::ExactFit, "view-menu-scale-mode-exact-fit"),
+            (ScaleMode::NoBorder, "view-menu-scale-mode-no-border"),
+            (ScaleMode::ShowAll, "view-menu-scale-mode-show-all"),
+            (ScaleMode::NoScale, "view-menu-scale-mode-no-scale"),
+        ];
+        for (scale_mode, id) in scale_modes {
+            let label = text(&self.locale, id);
+            if ui.radio_value(&mut self.scale_mode, scale_mode, label).clicked() {
+                self.dispatch(RuffleEvent::SetScaleMode(scale_mode));
+                ui.close_menu();
+            }
+        }
+        ui.separator();
+        let label = text(&self.locale, "view-menu-scale-mode-force");
+        if Checkbox::new(&mut self.force_scale_mode, label)
+            .ui(ui)
+            .clicked()
+        {
+            self.dispatch(RuffleEvent::SetForceScaleMode(self.force_scale_mode));
+            ui.close_menu();
+        }
+    }
+
+    /// Renders the contents of the "Language" submenu.
+    fn language_menu(&mut self, ui: &mut egui::Ui) {
+        let mut selected = None;
+        let mut is_automatic = self.language_override.is_none();
+        if Checkbox::new(
+            &mut is_automatic,
+            text(&self.locale, "settings-menu-language-automatic"),
+        )
+        .ui(ui)
+        .clicked()
+        {
+            selected = Some(None);
+        }
+
+        ui.separator();
+
+        for language in available_languages() {
+            let mut checked = self.language_override.as_ref() == Some(language);
+            if Checkbox::new(&mut checked, language_name(language))
+                .ui(ui)
+                .clicked()
+            {
+                selected = Some(Some(language.clone()));
+            }
+        }
+
+        if let Some(language) = selected {
+            self.set_language(language);
+            ui.close_menu();
+        }
+    }
+
+    /// Switches the GUI to the next available language, wrapping around after the last one.
+    /// Unlike picking a language from the menu, this isn't remembered for the next session.
+    fn cycle_language(&mut self) {
+        let languages = available_languages();
+        let next = languages
+            .iter()
+            .position(|language| **language == self.locale)
+            .map_or(0, |index| (index + 1) % languages.len());
+        let Some(&language) = languages.get(next) else {
+            return;
+        };
+        self.locale = language.clone();
+        self.language_override = Some(language.clone());
+        self.push_toast(language_name(language), ToastLevel::Info);
+    }
+
+    /// Switches the GUI to `language`, or back to the system locale if it's `None`.
+    fn set_language(&mut self, language: Option<LanguageIdentifier>) {
+        self.locale = resolve_locale(language.as_ref());
+        self.language_override = language.clone();
+        self.dispatch(RuffleEvent::SetLanguage(language));
+    }
+
+    fn reload_movie(&mut self, ui: &mut egui::Ui) {
+        self.dispatch(RuffleEvent::Reload);
+        ui.close_menu();
+    }
+
+    /// Copies where the movie was loaded from, as a path if it's a local file.
+    fn copy_location(&mut self, ui: &mut egui::Ui) {
+        ui.close_menu();
+        let Some(url) = &self.movie_url else {
+            return;
+        };
+        let location = url
+            .to_file_path()
+            .map_or_else(|()| url.to_string(), |path| path.display().to_string());
+        ui.output_mut(|output| output.copied_text = location);
+        self.push_toast(text(&self.locale, "location-copied").into_owned(), ToastLevel::Info);
+    }
+
+    fn close_movie(&mut self, ui: &mut egui::Ui) {
+        self.dispatch(RuffleEvent::CloseFile);
+        ui.close_menu();
+    }
+
+    fn take_screenshot(&mut self, ui: &mut egui::Ui) {
+        self.dispatch(RuffleEvent::TakeScreenshot);
+        ui.close_menu();
+    }
+
+    fn open_url_prompt(&mut self, egui_ctx: &egui::Context) {
+        let mut close_prompt = false;
+        let mut cancelled = false;
+        let mut open_event = None;
+        let mut fixed_url = None;
+        let mut clear_history = false;
+        // A copy, so that the window's contents can use `self` while the window holds on to it.
+        let mut is_open = self.is_open_url_prompt_visible;
+        egui::Window::new(text(&self.locale, "open-url"))
             .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
             .collapsible(false)
             .resizable(false)
-            .open(&mut self.is_open_url_prompt_visible)
+            .open(&mut is_open)
             .show(egui_ctx, |ui| {
                 ui.vertical_centered(|ui| {
                     let (enter_pressed, esc_pressed) = ui.ctx().input_mut(|input| {
@@ -318,38 +4130,294 @@ impl RuffleGui {
                             input.consume_key(Modifiers::NONE, Key::Escape),
                         )
                     });
-                    ui.text_edit_singleline(&mut self.open_url_text);
+                    let hint = text(&self.locale, "open-url-hint");
+                    let response = TextEdit::singleline(&mut self.open_url_text)
+                        .id(Id::new(OPEN_URL_TEXT_ID))
+                        .hint_text(hint)
+                        .ui(ui);
+                    if response.changed() {
+                        self.open_url_error = None;
+                    }
+                    if !self.url_history.is_empty() {
+                        ComboBox::from_id_source("open_url_history")
+                            .selected_text(text(&self.locale, "open-url-history"))
+                            .width(response.rect.width())
+                            .show_ui(ui, |ui| {
+                                for url in &self.url_history {
+                                    if ui.selectable_label(false, url).clicked() {
+                                        self.open_url_text = url.clone();
+                                        self.open_url_error = None;
+                                    }
+                                }
+                                ui.separator();
+                                if ui
+                                    .button(text(&self.locale, "open-url-clear-history"))
+                                    .clicked()
+                                {
+                                    clear_history = true;
+                                }
+                            });
+                    }
+                    CollapsingHeader::new(text(&self.locale, "open-url-parameters")).show(ui, |ui| {
+                        let mut removed = None;
+                        Grid::new("open_url_parameters").num_columns(3).show(ui, |ui| {
+                            for (index, (name, value)) in self.open_url_parameters.iter_mut().enumerate() {
+                                let name_response = TextEdit::singleline(name)
+                                    .hint_text(text(&self.locale, "open-url-parameter-name"))
+                                    .desired_width(100.0)
+                                    .ui(ui);
+                                let value_response = TextEdit::singleline(value)
+                                    .hint_text(text(&self.locale, "open-url-parameter-value"))
+                                    .desired_width(150.0)
+                                    .ui(ui);
+                                if name_response.changed() || value_response.changed() {
+                                    self.open_url_error = None;
+                                }
+                                if ui
+                                    .button("🗙")
+                                    .on_hover_text(text(&self.locale, "open-url-parameter-remove"))
+                                    .clicked()
+                                {
+                                    removed = Some(index);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                        if let Some(index) = removed {
+                            self.open_url_parameters.remove(index);
+                            self.open_url_error = None;
+                        }
+                        if ui.button(text(&self.locale, "open-url-parameter-add")).clicked() {
+                            self.open_url_parameters.push(Default::default());
+                        }
+                    });
+                    // Rows that were left completely empty are ignored.
+                    let parameters: Vec<_> = self
+                        .open_url_parameters
+                        .iter()
+                        .filter(|(name, value)| !name.is_empty() || !value.is_empty())
+                        .cloned()
+                        .collect();
+                    if let Some(error) = &self.open_url_error {
+                        ui.colored_label(Color32::RED, &error.message);
+                        if let Some(url) = &error.fix {
+                            let args = HashMap::from([("url", FluentValue::from(url.to_string()))]);
+                            if ui.button(text_with_args(&self.locale, "open-url-fix", &args)).clicked() {
+                                fixed_url = Some((url.clone(), parameters.clone()));
+                            }
+                        }
+                    }
                     ui.horizontal(|ui| {
                         if ui.button(text(&self.locale, "dialog-ok")).clicked() || enter_pressed {
-                            if let Ok(url) = url::Url::parse(&self.open_url_text) {
-                                let _ = self.event_loop.send_event(RuffleEvent::OpenURL(url));
+                            if parameters.iter().any(|(name, _)| name.trim().is_empty()) {
+                                self.open_url_error = Some(OpenUrlError {
+                                    message: text(&self.locale, "open-url-parameter-missing-name")
+                                        .into_owned(),
+                                    fix: None,
+                                });
                             } else {
-                                // TODO: Show error prompt.
-                                tracing::error!("Invalid URL: {}", self.open_url_text);
+                                match open_url_event(&self.locale, &self.open_url_text, parameters) {
+                                    Ok(event) => open_event = Some(event),
+                                    Err(error) => self.open_url_error = Some(error),
+                                }
                             }
-                            close_prompt = true;
                         }
                         if ui.button(text(&self.locale, "dialog-cancel")).clicked() || esc_pressed {
-                            close_prompt = true;
+                            cancelled = true;
                         }
                     });
                 });
             });
+        self.is_open_url_prompt_visible = is_open;
+        // Cancelling throws away what was typed, so the next prompt starts out empty.
+        if cancelled {
+            self.open_url_text.clear();
+            self.open_url_error = None;
+            close_prompt = true;
+        }
+        if clear_history {
+            self.url_history.clear();
+            self.dispatch(RuffleEvent::SetUrlHistory(vec![]));
+        }
+        if let Some((url, parameters)) = fixed_url {
+            self.open_url_text = url.to_string();
+            self.open_url_error = None;
+            open_event = Some(RuffleEvent::OpenURL(url, parameters));
+        }
+        if let Some(event) = open_event {
+            // Keep the prompt around if the request couldn't be delivered.
+            close_prompt = self.dispatch(event);
+            if close_prompt {
+                self.add_to_url_history(self.open_url_text.trim().to_string());
+            }
+        }
         if close_prompt {
             self.is_open_url_prompt_visible = false;
-        }*/
+        }
+    }
+
+    /// Moves an address to the top of the Open URL history, dropping the oldest past the limit.
+    fn add_to_url_history(&mut self, url: String) {
+        self.url_history.retain(|entry| *entry != url);
+        self.url_history.insert(0, url);
+        self.url_history.truncate(MAX_URL_HISTORY);
+        self.dispatch(RuffleEvent::SetUrlHistory(self.url_history.clone()));
+    }
+
+    fn clipboard_op(&mut self, ui: &mut egui::Ui, command: ClipboardCommand) {
+        self.dispatch(RuffleEvent::ClipboardOp(command));
+        ui.close_menu();
+    }
+
+    fn control_playback(&mut self, ui: &mut egui::Ui, command: PlaybackCommand) {
+        self.send_playback_command(command);
+        ui.close_menu();
+    }
+
+    /// Controls playback on behalf of the user, from the menus or from keys that the menus can't handle.
+    pub fn send_playback_command(&mut self, command: PlaybackCommand) {
+        // The user took over, so don't resume on their behalf later.
+        self.is_auto_paused = false;
+        self.show_play_overlay = false;
+        self.dispatch(RuffleEvent::PlaybackControl(command));
+    }
+
+    /// Sends the volume along with whether it's muted, so that unmuting restores
+    /// the volume that was set before.
+    fn send_volume(&mut self) {
+        self.dispatch(RuffleEvent::SetVolume {
+            volume: self.volume,
+            is_muted: self.is_muted,
+        });
+    }
+
+    fn send_default_volume(&mut self) {
+        self.dispatch(RuffleEvent::SetDefaultVolume {
+            volume: self.default_volume,
+            is_muted: self.is_muted,
+        });
+    }
+
+    /// Sets the volume shown in the Controls menu, without requesting a change.
+    pub fn set_volume(&mut self, volume: f32, is_muted: bool) {
+        self.volume = volume;
+        self.is_muted = is_muted;
+    }
+
+    /// Sets the volume and quality shown in the Preferences, without requesting a change.
+    pub fn set_defaults(&mut self, volume: f32, quality: StageQuality) {
+        self.default_volume = volume;
+        self.default_quality = quality;
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.is_muted
+    }
+
+    /// Shows the settings that a movie was loaded with, which may be its own rather than the last ones picked.
+    pub fn set_movie_settings(&mut self, quality: StageQuality, scale_mode: ScaleMode, zoom: f32) {
+        self.quality = quality;
+        self.scale_mode = scale_mode;
+        self.zoom = zoom;
+    }
+
+    /// Sends an event to the event loop, returning whether it was delivered.
+    /// Sending only fails once the event loop is gone, such as while exiting.
+    fn dispatch(&self, event: RuffleEvent) -> bool {
+        match self.event_loop.send_event(event) {
+            Ok(()) => true,
+            Err(event) => {
+                tracing::debug!("Couldn't dispatch {:?}, the event loop is gone", event);
+                false
+            }
+        }
+    }
+
+    fn toggle_fullscreen(&mut self) {
+        self.dispatch(RuffleEvent::ToggleFullscreen);
     }
 
     fn request_exit(&mut self, ui: &mut egui::Ui) {
-        let _ = self.event_loop.send_event(RuffleEvent::ExitRequested);
+        if !self.defer_exit() {
+            self.exit();
+        }
         ui.close_menu();
     }
 
+    /// Asks the event loop to exit, so it can save state on the way out.
+    /// Without an event loop to ask, the process is ended here instead of ignoring the request.
+    fn exit(&self) {
+        if !self.dispatch(RuffleEvent::ExitRequested) {
+            tracing::warn!("Exiting without the event loop");
+            std::process::exit(0);
+        }
+    }
+
+    /// Shows the exit confirmation instead of exiting, if it's enabled and a movie is loaded.
+    /// Returns whether the exit was deferred, in which case the dialog sends `ExitRequested` itself.
+    pub fn defer_exit(&mut self) -> bool {
+        let should_confirm = self.confirm_exit && self.movie_metadata.is_some();
+        if should_confirm {
+            self.is_exit_confirmation_visible = true;
+        }
+        should_confirm
+    }
+
+    fn exit_confirmation_window(&mut self, egui_ctx: &egui::Context) {
+        if !self.is_exit_confirmation_visible {
+            return;
+        }
+        let mut exit = false;
+        let mut cancel = false;
+        egui::Window::new(text(&self.locale, "exit-confirm-title"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.label(text(&self.locale, "exit-confirm-message"));
+                    ui.horizontal(|ui| {
+                        if ui.button(text(&self.locale, "exit-confirm-exit")).clicked() {
+                            exit = true;
+                        }
+                        if ui.button(text(&self.locale, "dialog-cancel")).clicked()
+                            || ui
+                                .ctx()
+                                .input_mut(|input| input.consume_key(Modifiers::NONE, Key::Escape))
+                        {
+                            cancel = true;
+                        }
+                    });
+                });
+            });
+        if exit {
+            self.is_exit_confirmation_visible = false;
+            self.exit();
+        } else if cancel {
+            self.is_exit_confirmation_visible = false;
+        }
+    }
+
     fn launch_website(&mut self, ui: &mut egui::Ui, url: &str) {
-        let _ = webbrowser::open(url);
+        self.open_website(url);
         ui.close_menu();
     }
 
+    /// Opens a website in the browser, offering to copy its address instead if that fails.
+    fn open_website(&mut self, url: &str) {
+        if let Err(e) = webbrowser::open(url) {
+            tracing::warn!("Couldn't open {url}: {e}");
+            self.unopened_url = Some(url.to_string());
+        }
+    }
+
+    /// Opens a local file or directory in whatever the system opens it with, such as a file manager.
+    fn open_path(&self, path: &Path) {
+        if let Err(e) = open::that(path) {
+            tracing::warn!("Couldn't open {}: {e}", path.display());
+        }
+    }
+
     fn show_about_screen(&mut self, ui: &mut egui::Ui) {
         self.is_about_visible = true;
         ui.close_menu();
@@ -357,6 +4425,289 @@ impl RuffleGui {
 
     fn show_open_url_prompt(&mut self, ui: &mut egui::Ui) {
         self.is_open_url_prompt_visible = true;
+        self.open_url_error = None;
+        ui.memory_mut(|mem| mem.request_focus(Id::new(OPEN_URL_TEXT_ID)));
         ui.close_menu();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A GUI with default preferences, and the receiving end of the events it dispatches.
+    fn test_gui() -> (RuffleGui, std::sync::mpsc::Receiver<RuffleEvent>) {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let gui = RuffleGui::new(
+            EventSender::Channel(sender),
+            &Preferences::default(),
+            LogBuffer::default(),
+        );
+        (gui, receiver)
+    }
+
+    #[test]
+    fn dispatch_delivers_events() {
+        let (gui, receiver) = test_gui();
+        assert!(gui.dispatch(RuffleEvent::ToggleFullscreen));
+        assert!(matches!(receiver.try_recv(), Ok(RuffleEvent::ToggleFullscreen)));
+    }
+
+    #[test]
+    fn dispatch_fails_once_event_loop_is_gone() {
+        let (gui, receiver) = test_gui();
+        drop(receiver);
+        assert!(!gui.dispatch(RuffleEvent::ToggleFullscreen));
+    }
+
+    #[test]
+    fn reset_preferences_changes_defaults_rather_than_movie_settings() {
+        let (mut gui, receiver) = test_gui();
+        gui.reset_preferences();
+        let events: Vec<_> = receiver.try_iter().collect();
+        assert!(events.iter().any(|event| matches!(event, RuffleEvent::SetDefaultQuality(_))));
+        assert!(events.iter().any(|event| matches!(event, RuffleEvent::SetDefaultVolume { .. })));
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, RuffleEvent::SetQuality(_) | RuffleEvent::SetVolume { .. })));
+    }
+
+    /// A GUI playing the given entry of a playlist of three movies.
+    fn playlist_gui(position: usize) -> (RuffleGui, std::sync::mpsc::Receiver<RuffleEvent>) {
+        let (mut gui, receiver) = test_gui();
+        gui.playlist = ["a", "b", "c"]
+            .iter()
+            .map(|name| Url::parse(&format!("file:///{name}.json")).expect("Valid URL"))
+            .collect();
+        gui.playlist_position = Some(position);
+        (gui, receiver)
+    }
+
+    /// The movie that the GUI asked to be opened, if any.
+    fn opened_url(receiver: &std::sync::mpsc::Receiver<RuffleEvent>) -> Option<Url> {
+        receiver.try_iter().find_map(|event| match event {
+            RuffleEvent::OpenURL(url, _) => Some(url),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn movie_playback_ended_plays_next_entry() {
+        let (mut gui, receiver) = playlist_gui(0);
+        gui.movie_playback_ended();
+        assert_eq!(opened_url(&receiver), Some(gui.playlist[1].clone()));
+        assert_eq!(gui.playlist_position, Some(1));
+    }
+
+    #[test]
+    fn movie_playback_ended_loops_back_to_first_entry() {
+        let (mut gui, receiver) = playlist_gui(2);
+        gui.loop_playlist = true;
+        gui.movie_playback_ended();
+        assert_eq!(opened_url(&receiver), Some(gui.playlist[0].clone()));
+        assert_eq!(gui.playlist_position, Some(0));
+    }
+
+    #[test]
+    fn movie_playback_ended_stops_after_last_entry() {
+        let (mut gui, receiver) = playlist_gui(2);
+        gui.movie_playback_ended();
+        assert_eq!(opened_url(&receiver), None);
+        assert_eq!(gui.playlist_position, Some(2));
+    }
+
+    #[test]
+    fn movie_playback_ended_stays_without_auto_advance() {
+        let (mut gui, receiver) = playlist_gui(0);
+        gui.auto_advance = false;
+        gui.movie_playback_ended();
+        assert_eq!(opened_url(&receiver), None);
+        assert_eq!(gui.playlist_position, Some(0));
+    }
+
+    #[test]
+    fn movie_playback_ended_ignores_movies_outside_playlist() {
+        let (mut gui, receiver) = playlist_gui(0);
+        gui.playlist_position = None;
+        gui.movie_playback_ended();
+        assert_eq!(opened_url(&receiver), None);
+    }
+
+    /// Runs a frame of the menu bar with `shortcut` pressed, the way the window hands key presses to egui.
+    fn press_in_menu_bar(gui: &mut RuffleGui, shortcut: KeyboardShortcut) {
+        let input = egui::RawInput {
+            events: vec![egui::Event::Key {
+                key: shortcut.key,
+                pressed: true,
+                repeat: false,
+                modifiers: shortcut.modifiers,
+            }],
+            modifiers: shortcut.modifiers,
+            ..Default::default()
+        };
+        let _ = egui::Context::default().run(input, |egui_ctx| gui.main_menu_bar(egui_ctx, None, false));
+    }
+
+    #[test]
+    fn zoom_shortcuts_send_zoom_events() {
+        let (mut gui, receiver) = test_gui();
+        // The zoom the view is at, and the zoom that pressing the shortcut should go to.
+        let cases = [
+            (ShortcutAction::ZoomIn, 1.0, 1.5),
+            (ShortcutAction::ZoomOut, 1.0, 0.75),
+            (ShortcutAction::ZoomReset, 2.0, 1.0),
+        ];
+        let declared = gui.zoom_menu_items().map(|item| item.shortcut);
+        assert_eq!(declared, cases.map(|(action, _, _)| Some(gui.shortcuts.get(action))));
+        for (action, from, to) in cases {
+            gui.zoom = from;
+            let shortcut = gui.shortcuts.get(action);
+            press_in_menu_bar(&mut gui, shortcut);
+            let zooms: Vec<_> = receiver
+                .try_iter()
+                .filter_map(|event| match event {
+                    RuffleEvent::SetZoom(zoom) => Some(zoom),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(zooms, [to], "{action:?}");
+        }
+    }
+
+    fn languages(ids: &[&str]) -> Vec<LanguageIdentifier> {
+        ids.iter().map(|id| id.parse().expect("Valid language id")).collect()
+    }
+
+    #[test]
+    fn negotiate_locale_exact_match() {
+        let available = languages(&["de-DE", "en-US", "fr-FR"]);
+        let available: Vec<_> = available.iter().collect();
+        let locale = negotiate_locale_among(&languages(&["fr-FR", "de-DE"]), &available);
+        assert_eq!(locale, langid!("fr-FR"));
+    }
+
+    #[test]
+    fn negotiate_locale_falls_back_to_same_language() {
+        let available = languages(&["en-US", "fr-FR"]);
+        let available: Vec<_> = available.iter().collect();
+        let locale = negotiate_locale_among(&languages(&["fr-CA"]), &available);
+        assert_eq!(locale, langid!("fr-FR"));
+    }
+
+    #[test]
+    fn negotiate_locale_falls_back_to_english_when_unsupported() {
+        let available = languages(&["en-US", "fr-FR"]);
+        let available: Vec<_> = available.iter().collect();
+        let locale = negotiate_locale_among(&languages(&["ja-JP"]), &available);
+        assert_eq!(locale, US_ENGLISH);
+    }
+
+    #[test]
+    fn negotiate_locale_falls_back_to_english_when_nothing_requested() {
+        let available = languages(&["en-US", "fr-FR"]);
+        let available: Vec<_> = available.iter().collect();
+        assert_eq!(negotiate_locale_among(&[], &available), US_ENGLISH);
+        assert_eq!(negotiate_locale(&[]), US_ENGLISH);
+    }
+
+    #[test]
+    fn try_text_finds_existing_text() {
+        assert_eq!(try_text(&US_ENGLISH, "file-menu").as_deref(), Some("File"));
+    }
+
+    /// Whether a string literal is written the way text ids are, in lowercase words joined by dashes.
+    fn is_text_id(literal: &str) -> bool {
+        literal.contains('-')
+            && literal.split('-').all(|word| {
+                word.starts_with(|c: char| c.is_ascii_lowercase())
+                    && word.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            })
+    }
+
+    /// Every text id that the GUI asks for, as it's written in this file's source, outside of the tests.
+    /// Nothing else in the GUI is written like a text id, so this finds the ids in every menu and window,
+    /// including the ones in tables such as `QUALITY_OPTIONS`.
+    fn text_ids_in_source() -> Vec<&'static str> {
+        let source = include_str!("gui.rs");
+        let source = &source[..source.find("#[cfg(test)]\nmod tests").unwrap_or(source.len())];
+        source
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .flat_map(|line| line.split('"').skip(1).step_by(2))
+            .filter(|literal| is_text_id(literal))
+            .collect()
+    }
+
+    #[test]
+    fn every_text_id_exists_in_english() {
+        let ids = text_ids_in_source();
+        // Make sure that the ids of the menu bar and the About window are being found at all.
+        for id in ["file-menu", "view-menu-zoom-in", "help-menu-check-for-updates", "about-ruffle-version"] {
+            assert!(ids.contains(&id), "{id} wasn't found in the source");
+        }
+        let missing: Vec<_> = ids
+            .into_iter()
+            .filter(|&id| try_text(&US_ENGLISH, id).is_none())
+            .collect();
+        assert!(missing.is_empty(), "No English text for {missing:?}");
+    }
+
+    /// How many texts have been resolved through Fluent on this thread so far.
+    fn text_lookups() -> usize {
+        TEXT_LOOKUPS.with(Cell::get)
+    }
+
+    #[test]
+    fn rendering_again_reuses_cached_text() {
+        let (mut gui, _receiver) = test_gui();
+        gui.locale = US_ENGLISH.clone();
+        let mut render = || {
+            let input = egui::RawInput::default();
+            let _ = egui::Context::default()
+                .run(input, |egui_ctx| gui.main_menu_bar(egui_ctx, None, false));
+        };
+        render();
+        let lookups = text_lookups();
+        render();
+        assert_eq!(text_lookups(), lookups);
+    }
+
+    #[test]
+    fn text_is_looked_up_again_when_language_changes() {
+        assert_eq!(text(&US_ENGLISH, "file-menu"), "File");
+        let lookups = text_lookups();
+        // Only English is bundled, which any other language falls back to.
+        assert_eq!(text(&langid!("fr-FR"), "file-menu"), "File");
+        assert_eq!(text_lookups(), lookups + 1);
+    }
+
+    #[test]
+    fn try_text_returns_none_for_missing_text() {
+        assert_eq!(try_text(&US_ENGLISH, "no-such-text-id"), None);
+    }
+
+    #[test]
+    fn format_timestamp_formats_valid_timestamp() {
+        let formatted = format_timestamp("2023-06-15T12:34:56Z");
+        assert_ne!(formatted, "2023-06-15T12:34:56Z");
+        assert!(formatted.contains("2023"), "{formatted}");
+    }
+
+    #[test]
+    fn format_timestamp_passes_invalid_timestamp_through() {
+        assert_eq!(format_timestamp("not a timestamp"), "not a timestamp");
+        assert_eq!(format_timestamp(""), "");
+    }
+
+    #[test]
+    fn format_timestamp_follows_time_zone_offset() {
+        assert_eq!(
+            format_timestamp("2023-06-15T14:34:56+02:00"),
+            format_timestamp("2023-06-15T12:34:56Z")
+        );
+        assert_ne!(
+            format_timestamp("2023-06-15T12:34:56+02:00"),
+            format_timestamp("2023-06-15T12:34:56Z")
+        );
+    }
+}
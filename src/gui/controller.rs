@@ -1,18 +1,122 @@
-use crate::custom_event::RuffleEvent;
+use crate::custom_event::{ClipboardCommand, PlaybackCommand, RuffleEvent};
+use crate::console::LogBuffer;
+use crate::editor::main::ScaleMode;
+use crate::editor::player::{ContextMenuItem, MovieMetadata, Player};
 use crate::gui::movie::{MovieView, MovieViewRenderer};
-use crate::gui::RuffleGui;
+#[cfg(feature = "gamepad")]
+use crate::gamepad::{GamepadButton, GamepadMapping};
+use crate::gui::{EventSender, RuffleGui, StageTransform, MENU_HEIGHT};
+use crate::preferences::{may_save, MaxFps, Preferences, WindowGeometry};
+use crate::update::Release;
+use crate::util::{
+    pick_console_log_path, pick_screenshot_path, system_prefers_reduced_motion, system_theme,
+};
 use anyhow::anyhow;
 use egui::Context;
-use ruffle_render_wgpu::backend::request_adapter_and_device;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use ruffle_render::quality::StageQuality;
+use ruffle_render_wgpu::backend::{request_adapter_and_device, WgpuRenderBackend};
 use ruffle_render_wgpu::descriptors::Descriptors;
 use ruffle_render_wgpu::utils::{format_list, get_backend_names};
-use std::path::Path;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
-use std::sync::Arc;
+use std::sync::{Arc, MutexGuard};
 use std::time::{Duration, Instant};
-use winit::dpi::PhysicalSize;
-use winit::event_loop::EventLoop;
-use winit::window::Window;
+use url::Url;
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event::MouseScrollDelta;
+use winit::event_loop::{EventLoop, EventLoopProxy};
+use winit::window::{Fullscreen, Window, WindowLevel};
+
+/// How often connected gamepads are checked on, often enough for their buttons not to lag behind.
+#[cfg(feature = "gamepad")]
+const GAMEPAD_POLL_INTERVAL: Duration = Duration::from_millis(8);
+
+/// How often gamepads being plugged in are looked for, while none are.
+#[cfg(feature = "gamepad")]
+const GAMEPAD_CONNECT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How many pixels of smooth scrolling, as trackpads send it, make up one line of scrolling.
+const PIXELS_PER_SCROLL_LINE: f64 = 20.0;
+
+/// How long a watched file has to stay unchanged before it's reloaded, as saving it may take several writes.
+const WATCHED_FILE_RELOAD_DELAY: Duration = Duration::from_millis(300);
+
+/// Frames of the movie being saved one per redraw, see [`GuiController::record_frames`].
+struct Recording {
+    dir: PathBuf,
+    count: u32,
+    saved: u32,
+    /// Whether the movie was playing before, so that it can be resumed afterwards.
+    was_playing: bool,
+}
+
+/// Watches the given file, sending `RuffleEvent::WatchedFileChanged` whenever it changes.
+/// Its directory is watched rather than the file itself, as many editors save by replacing the file.
+fn watch_file(
+    path: &Path,
+    event_loop: EventLoopProxy<RuffleEvent>,
+) -> notify::Result<RecommendedWatcher> {
+    let file = path.to_path_buf();
+    let mut watcher =
+        notify::recommended_watcher(move |result: notify::Result<notify::Event>| match result {
+            Ok(event) => {
+                if (event.kind.is_create() || event.kind.is_modify()) && event.paths.contains(&file)
+                {
+                    let _ = event_loop.send_event(RuffleEvent::WatchedFileChanged);
+                }
+            }
+            Err(e) => tracing::warn!("Error while watching {}: {e}", file.display()),
+        })?;
+    watcher.watch(path.parent().unwrap_or(path), RecursiveMode::NonRecursive)?;
+    Ok(watcher)
+}
+
+/// Reads a movie from standard input on a background thread, so that a pipe that's slow to close doesn't hold up
+/// the window, and opens it once it's all been read.
+fn read_stdin(event_loop: EventLoopProxy<RuffleEvent>) {
+    std::thread::spawn(move || {
+        let mut data = Vec::new();
+        match std::io::stdin().lock().read_to_end(&mut data) {
+            Ok(_) if data.is_empty() => tracing::info!("Nothing was piped into standard input"),
+            Ok(_) => {
+                let _ = event_loop.send_event(RuffleEvent::OpenBytes {
+                    name: "<stdin>".to_string(),
+                    data,
+                });
+            }
+            Err(e) => tracing::error!("Couldn't read standard input: {e}"),
+        }
+    });
+}
+
+/// Reads whether the system asks for animations to be reduced on a background thread, as that may mean running a
+/// command-line tool, and lets the GUI know once it's been read.
+fn read_system_reduced_motion(event_loop: EventLoopProxy<RuffleEvent>) {
+    std::thread::spawn(move || {
+        if let Some(reduces_motion) = system_prefers_reduced_motion() {
+            let _ = event_loop.send_event(RuffleEvent::SystemReducesMotion(reduces_motion));
+        }
+    });
+}
+
+/// Reads the system's light or dark preference on a background thread, as that means running a command-line tool,
+/// and lets the GUI know once it's been read. Only needed where winit can't tell it.
+fn read_system_theme(event_loop: EventLoopProxy<RuffleEvent>) {
+    std::thread::spawn(move || {
+        if let Some(theme) = system_theme() {
+            let _ = event_loop.send_event(RuffleEvent::SystemTheme(theme));
+        }
+    });
+}
+
+/// A movie to open, either from a local path, from a URL, or piped in through standard input.
+pub enum MovieSource {
+    Path(PathBuf),
+    Url(Url),
+    Stdin,
+}
 
 /// Integration layer connecting wgpu+winit to egui.
 pub struct GuiController {
@@ -21,12 +125,48 @@ pub struct GuiController {
     egui_winit: egui_winit::State,
     egui_renderer: egui_wgpu::renderer::Renderer,
     gui: RuffleGui,
+    event_loop: EventLoopProxy<RuffleEvent>,
     window: Rc<Window>,
     last_update: Instant,
+    /// When the last frame was rendered, to measure frame times.
+    last_frame_at: Option<Instant>,
     repaint_after: Duration,
     surface: wgpu::Surface,
     surface_format: wgpu::TextureFormat,
     movie_view_renderer: Arc<MovieViewRenderer>,
+    /// The menu height that the movie view was last laid out with.
+    movie_menu_height: u32,
+    /// The status bar height that the movie view was last laid out with.
+    movie_status_bar_height: u32,
+    recording: Option<Recording>,
+    /// How often the window may be redrawn.
+    max_fps: MaxFps,
+    /// Whether the window is kept above other windows, while it isn't fullscreen.
+    always_on_top: bool,
+    /// How the window was fullscreen before kiosk mode was entered, to go back to when it's left.
+    fullscreen_before_kiosk: Option<Fullscreen>,
+    /// Whether scrolling goes to the movie, rather than zooming the view.
+    forward_mouse_wheel: bool,
+    /// Scrolling towards a zoom step that hasn't added up to a whole line yet.
+    wheel_zoom_lines: f64,
+    /// Where the last screenshot was saved, for the next one to be saved next to it.
+    last_screenshot_directory: Option<PathBuf>,
+    /// Whether the loaded movie is reloaded whenever its file changes.
+    watch_file: bool,
+    /// Watches the file of the loaded movie, if it's being watched and is a local file.
+    file_watcher: Option<RecommendedWatcher>,
+    /// When the watched file last changed, if it hasn't been reloaded since.
+    watched_file_changed_at: Option<Instant>,
+    /// The movie to open once the first frame has been shown, see [`GuiController::open_on_startup`].
+    initial_movie: Option<MovieSource>,
+    /// Gamepad input, or `None` if it couldn't be set up on this system.
+    #[cfg(feature = "gamepad")]
+    gilrs: Option<gilrs::Gilrs>,
+    #[cfg(feature = "gamepad")]
+    gamepad_mapping: GamepadMapping,
+    /// Presses and releases of the keys that gamepad buttons are mapped to, for the next frame's input.
+    #[cfg(feature = "gamepad")]
+    gamepad_key_events: Vec<egui::Event>,
     // Note that `window.get_inner_size` can change at any point on x11, even between two lines of code.
     // Use this instead.
     size: PhysicalSize<u32>,
@@ -39,6 +179,8 @@ impl GuiController {
         trace_path: Option<&Path>,
         backend: wgpu::Backends,
         power_preference: wgpu::PowerPreference,
+        preferences: &Preferences,
+        log_buffer: LogBuffer,
     ) -> anyhow::Result<Self> {
         if wgpu::Backends::SECONDARY.contains(backend) {
             tracing::warn!(
@@ -81,34 +223,225 @@ impl GuiController {
         let descriptors = Descriptors::new(instance, adapter, device, queue);
         let egui_ctx = Context::default();
         let mut egui_winit = egui_winit::State::new(event_loop);
-        egui_winit.set_pixels_per_point(window.scale_factor() as f32);
+        egui_winit.set_pixels_per_point(window.scale_factor() as f32 * preferences.ui_scale);
         egui_winit.set_max_texture_side(descriptors.limits.max_texture_dimension_2d as usize);
 
+        let menu_height = if window.fullscreen().is_none() && !preferences.hide_menu_bar {
+            (MENU_HEIGHT as f32 * preferences.ui_scale).round() as u32
+        } else {
+            0
+        };
         let movie_view_renderer = Arc::new(MovieViewRenderer::new(
             &descriptors.device,
             surface_format,
-            window.fullscreen().is_none(),
+            menu_height,
+            0,
             size.height,
         ));
         let egui_renderer = egui_wgpu::Renderer::new(&descriptors.device, surface_format, None, 1);
         let event_loop = event_loop.create_proxy();
-        let gui = RuffleGui::new(event_loop);
+        let mut gui = RuffleGui::new(
+            EventSender::EventLoop(event_loop.clone()),
+            preferences,
+            log_buffer,
+        );
+        match window.theme() {
+            Some(theme) => gui.set_system_theme(Some(theme)),
+            None => read_system_theme(event_loop.clone()),
+        }
+        read_system_reduced_motion(event_loop.clone());
+        gui.set_preferences_saved(may_save());
+        if preferences.always_on_top {
+            window.set_window_level(WindowLevel::AlwaysOnTop);
+        }
         Ok(Self {
             descriptors: Arc::new(descriptors),
             egui_ctx,
             egui_winit,
             egui_renderer,
             gui,
+            event_loop,
             window,
             last_update: Instant::now(),
+            last_frame_at: None,
             repaint_after: Duration::ZERO,
             surface,
             surface_format,
             movie_view_renderer,
+            movie_menu_height: menu_height,
+            movie_status_bar_height: 0,
+            recording: None,
+            max_fps: preferences.max_fps,
+            always_on_top: preferences.always_on_top,
+            fullscreen_before_kiosk: None,
+            forward_mouse_wheel: preferences.forward_mouse_wheel,
+            wheel_zoom_lines: 0.0,
+            last_screenshot_directory: None,
+            watch_file: false,
+            file_watcher: None,
+            watched_file_changed_at: None,
+            initial_movie: None,
+            #[cfg(feature = "gamepad")]
+            gilrs: gilrs::Gilrs::new()
+                .map_err(|e| tracing::warn!("Couldn't set up gamepad support: {e}"))
+                .ok(),
+            #[cfg(feature = "gamepad")]
+            gamepad_mapping: preferences.gamepad_mapping.clone(),
+            #[cfg(feature = "gamepad")]
+            gamepad_key_events: vec![],
             size,
         })
     }
 
+    /// Returns the current position and size of the window, if it's in a state worth remembering.
+    pub fn window_geometry(&self) -> Option<WindowGeometry> {
+        if self.window.fullscreen().is_some() || self.size.width == 0 || self.size.height == 0 {
+            return None;
+        }
+        // Some platforms, like Wayland, don't let us know where the window is,
+        // but its size is still worth keeping.
+        let position = self.window.outer_position().ok();
+        Some(WindowGeometry {
+            x: position.map(|position| position.x),
+            y: position.map(|position| position.y),
+            width: self.size.width,
+            height: self.size.height,
+        })
+    }
+
+    /// Resizes the window so that a stage of the given size, in logical pixels, fits it exactly,
+    /// as long as that fits on the window's monitor.
+    pub fn match_window_to_movie(&self, width: f64, height: f64) {
+        if self.window.fullscreen().is_some() {
+            return;
+        }
+        let scale_factor = self.window.scale_factor();
+        let mut size = PhysicalSize::new(
+            (width * scale_factor).round().max(1.0) as u32,
+            (height * scale_factor).round().max(1.0) as u32
+                + self.menu_height()
+                + self.gui.bottom_panel_height(),
+        );
+        // winit doesn't expose the monitor's work area, so its full size is the best we can do.
+        if let Some(monitor) = self.window.current_monitor() {
+            let monitor_size = monitor.size();
+            size.width = size.width.min(monitor_size.width);
+            size.height = size.height.min(monitor_size.height);
+        }
+        self.window.set_inner_size(size);
+    }
+
+    /// Makes the window fullscreen on its current monitor, laying the movie out without a menu bar right away
+    /// rather than waiting for the window to be resized.
+    pub fn enter_fullscreen(&mut self) {
+        self.window
+            .set_fullscreen(Some(Fullscreen::Borderless(None)));
+        self.movie_menu_height = self.menu_height();
+        self.movie_view_renderer.update_resolution(
+            &self.descriptors,
+            self.movie_menu_height,
+            self.movie_status_bar_height,
+            self.size.height,
+        );
+    }
+
+    /// Moves and resizes the window to a previously saved geometry,
+    /// pulling it back onto a monitor if it would end up off-screen.
+    pub fn restore_window_geometry(&self, geometry: WindowGeometry) {
+        let size = PhysicalSize::new(geometry.width, geometry.height);
+        let (Some(x), Some(y)) = (geometry.x, geometry.y) else {
+            // Without a position, the system places the window, so it only has to fit on its monitor.
+            let size = match self.window.current_monitor() {
+                Some(monitor) => PhysicalSize::new(
+                    size.width.min(monitor.size().width),
+                    size.height.min(monitor.size().height),
+                ),
+                None => size,
+            };
+            self.window.set_inner_size(size);
+            return;
+        };
+        let (position, size) = self.clamp_to_monitors(PhysicalPosition::new(x, y), size);
+        self.window.set_inner_size(size);
+        self.window.set_outer_position(position);
+    }
+
+    /// Fits the window inside the monitor closest to its center,
+    /// in case that monitor has since been disconnected or rearranged.
+    fn clamp_to_monitors(
+        &self,
+        position: PhysicalPosition<i32>,
+        size: PhysicalSize<u32>,
+    ) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        let center_x = position.x as i64 + size.width as i64 / 2;
+        let center_y = position.y as i64 + size.height as i64 / 2;
+        let distance_to = |monitor: &winit::monitor::MonitorHandle| {
+            let position = monitor.position();
+            let size = monitor.size();
+            let (left, top) = (position.x as i64, position.y as i64);
+            let (right, bottom) = (left + size.width as i64, top + size.height as i64);
+            let dx = (left - center_x).max(center_x - right).max(0);
+            let dy = (top - center_y).max(center_y - bottom).max(0);
+            dx * dx + dy * dy
+        };
+        let Some(monitor) = self.window.available_monitors().min_by_key(distance_to) else {
+            return (position, size);
+        };
+        let monitor_position = monitor.position();
+        let monitor_size = monitor.size();
+        let width = size.width.min(monitor_size.width);
+        let height = size.height.min(monitor_size.height);
+        let x = position.x.clamp(
+            monitor_position.x,
+            monitor_position.x + (monitor_size.width - width) as i32,
+        );
+        let y = position.y.clamp(
+            monitor_position.y,
+            monitor_position.y + (monitor_size.height - height) as i32,
+        );
+        (PhysicalPosition::new(x, y), PhysicalSize::new(width, height))
+    }
+
+    /// How positions in the window map to coordinates on the movie's stage, as the movie is currently laid out.
+    /// The movie view is stretched over the area between the menu and status bars, and the stage is placed
+    /// within the movie view by its view matrix.
+    fn stage_transform(&self, player: &Player) -> Option<StageTransform> {
+        let (viewport_width, viewport_height) = player.viewport_size();
+        let view_height = self
+            .size
+            .height
+            .checked_sub(self.movie_menu_height + self.movie_status_bar_height)
+            .filter(|&height| height > 0)?;
+        let view_matrix = player.view_matrix();
+        if self.size.width == 0 || view_matrix.a == 0.0 || view_matrix.d == 0.0 {
+            return None;
+        }
+        let pixels_per_point = self.egui_ctx.pixels_per_point();
+        let to_viewport_x = viewport_width as f32 / self.size.width as f32;
+        let to_viewport_y = viewport_height as f32 / view_height as f32;
+        Some(StageTransform {
+            origin: egui::pos2(
+                view_matrix.tx.to_pixels() as f32 / to_viewport_x / pixels_per_point,
+                (self.movie_menu_height as f32 + view_matrix.ty.to_pixels() as f32 / to_viewport_y)
+                    / pixels_per_point,
+            ),
+            scale: egui::vec2(
+                pixels_per_point * to_viewport_x / view_matrix.a,
+                pixels_per_point * to_viewport_y / view_matrix.d,
+            ),
+        })
+    }
+
+    /// The height of the menu bar in physical pixels, or 0 if it's hidden.
+    /// When it's hidden, it's only ever revealed on top of the movie, so the movie doesn't move.
+    pub fn menu_height(&self) -> u32 {
+        if self.window.fullscreen().is_some() || self.gui.is_menu_bar_hidden() {
+            0
+        } else {
+            self.gui.menu_height()
+        }
+    }
+
     pub fn descriptors(&self) -> &Arc<Descriptors> {
         &self.descriptors
     }
@@ -128,20 +461,70 @@ impl GuiController {
                     view_formats: Default::default(),
                 },
             );
+            self.movie_menu_height = self.menu_height();
+            self.movie_status_bar_height = self.gui.bottom_panel_height();
             self.movie_view_renderer.update_resolution(
                 &self.descriptors,
-                self.window.fullscreen().is_none(),
+                self.movie_menu_height,
+                self.movie_status_bar_height,
                 size.height,
             );
             self.size = *size;
         }
+        match event {
+            winit::event::WindowEvent::HoveredFile(path) => {
+                self.gui.set_hovered_file(Some(path.clone()));
+                self.window.request_redraw();
+            }
+            winit::event::WindowEvent::HoveredFileCancelled => {
+                self.gui.set_hovered_file(None);
+                self.window.request_redraw();
+            }
+            winit::event::WindowEvent::DroppedFile(path) => {
+                self.gui.drop_file(path.clone());
+                self.window.request_redraw();
+            }
+            winit::event::WindowEvent::CloseRequested => {
+                // Hold the close back while the user is asked to confirm it.
+                if self.gui.defer_exit() {
+                    self.window.request_redraw();
+                    return true;
+                }
+            }
+            winit::event::WindowEvent::ThemeChanged(theme) => {
+                self.gui.set_system_theme(Some(*theme));
+                self.window.request_redraw();
+            }
+            winit::event::WindowEvent::Focused(is_focused) => {
+                self.gui.set_window_focused(*is_focused);
+                // Without ThemeChanged events, pick up a change made while the user was away in the settings.
+                if *is_focused && self.window.theme().is_none() {
+                    read_system_theme(self.event_loop.clone());
+                }
+                self.window.request_redraw();
+            }
+            _ => {}
+        }
         let response = self.egui_winit.on_event(&self.egui_ctx, event);
+        if let winit::event::WindowEvent::ScaleFactorChanged { .. } = event {
+            // egui-winit has just gone back to the window's scale factor, without the GUI's scale.
+            self.update_pixels_per_point();
+        }
         if response.repaint {
             self.window.request_redraw();
         }
         response.consumed
     }
 
+    /// Has egui draw at the window's scale factor, times the GUI's own scale.
+    /// Both egui and egui-winit are told, so that input gets placed at the same scale that's drawn at.
+    /// Scaling the GUI only changes how large our own chrome is drawn, the movie view keeps its own size.
+    fn update_pixels_per_point(&mut self) {
+        let pixels_per_point = self.window.scale_factor() as f32 * self.gui.ui_scale();
+        self.egui_winit.set_pixels_per_point(pixels_per_point);
+        self.egui_ctx.set_pixels_per_point(pixels_per_point);
+    }
+
     pub fn create_movie_view(&self) -> MovieView {
         MovieView::new(
             self.movie_view_renderer.clone(),
@@ -151,19 +534,59 @@ impl GuiController {
         )
     }
 
-    pub fn render(&mut self, movie: Option<&MovieView>) {
+    pub fn render(&mut self, mut player: Option<MutexGuard<Player>>) {
         let surface_texture = self
             .surface
             .get_current_texture()
             .expect("Surface became unavailable");
 
-        let raw_input = self.egui_winit.take_egui_input(&self.window);
+        // The movie has just been rendered, so this is the moment to save it if it's being recorded.
+        if self.recording.is_some() {
+            self.record_frame(player.as_deref_mut());
+        }
+
+        let now = Instant::now();
+        if let Some(last_frame_at) = self.last_frame_at {
+            self.gui.set_frame_time(now - last_frame_at);
+        }
+        self.last_frame_at = Some(now);
+
+        let stage_transform = player
+            .as_deref()
+            .and_then(|player| self.stage_transform(player));
+        self.gui.set_stage_transform(stage_transform);
+        self.gui
+            .set_scrollbars(player.as_deref().map_or((None, None), Player::scrollbars));
+
+        // The GUI's scale may have been changed last frame.
+        self.update_pixels_per_point();
+        #[allow(unused_mut)]
+        let mut raw_input = self.egui_winit.take_egui_input(&self.window);
+        #[cfg(feature = "gamepad")]
+        raw_input.events.append(&mut self.gamepad_key_events);
+        let is_fullscreen = self.window.fullscreen().is_some();
         let full_output = self.egui_ctx.run(raw_input, |context| {
             self.gui
-                .update(context, self.window.fullscreen().is_none(), movie.is_some());
+                .update(context, !is_fullscreen, player.as_deref(), is_fullscreen);
         });
         self.repaint_after = full_output.repaint_after;
 
+        // The menu and status bars may have changed size, now that they have been laid out again.
+        let menu_height = self.menu_height();
+        let status_bar_height = self.gui.bottom_panel_height();
+        if menu_height != self.movie_menu_height
+            || status_bar_height != self.movie_status_bar_height
+        {
+            self.movie_menu_height = menu_height;
+            self.movie_status_bar_height = status_bar_height;
+            self.movie_view_renderer.update_resolution(
+                &self.descriptors,
+                menu_height,
+                status_bar_height,
+                self.size.height,
+            );
+        }
+
         self.egui_winit.handle_platform_output(
             &self.window,
             &self.egui_ctx,
@@ -171,10 +594,9 @@ impl GuiController {
         );
         let clipped_primitives = self.egui_ctx.tessellate(full_output.shapes);
 
-        let scale_factor = self.window.scale_factor() as f32;
         let screen_descriptor = egui_wgpu::renderer::ScreenDescriptor {
             size_in_pixels: [self.size.width, self.size.height],
-            pixels_per_point: scale_factor,
+            pixels_per_point: self.egui_ctx.pixels_per_point(),
         };
 
         let mut encoder =
@@ -217,8 +639,14 @@ impl GuiController {
                 label: Some("egui_render"),
             });
 
-            if let Some(movie) = movie {
-                movie.render(&self.movie_view_renderer, &mut render_pass);
+            if let Some(player) = player.as_deref_mut() {
+                let renderer = player
+                    .renderer_mut()
+                    .downcast_mut::<WgpuRenderBackend<MovieView>>()
+                    .expect("Renderer must be correct type");
+                renderer
+                    .target()
+                    .render(&self.movie_view_renderer, &mut render_pass);
             }
 
             self.egui_renderer
@@ -232,15 +660,506 @@ impl GuiController {
         command_buffers.push(encoder.finish());
         self.descriptors.queue.submit(command_buffers);
         surface_texture.present();
+
+        if let Some(source) = self.initial_movie.take() {
+            match source {
+                MovieSource::Path(path) => {
+                    self.gui.dispatch(RuffleEvent::OpenFile(path));
+                }
+                MovieSource::Url(url) => {
+                    self.gui.dispatch(RuffleEvent::OpenURL(url, vec![]));
+                }
+                MovieSource::Stdin => read_stdin(self.event_loop.clone()),
+            }
+        }
+    }
+
+    /// Opens the given movie once the window has been shown, through the same events as opening it from the menu,
+    /// so that it's handled just as if the user had opened it themselves.
+    pub fn open_on_startup(&mut self, source: MovieSource) {
+        self.initial_movie = Some(source);
+        self.window.request_redraw();
+    }
+
+    /// Saves the last rendered frame of the movie as a PNG, asking the user where to put it.
+    pub fn take_screenshot(&mut self, player: &mut Player) {
+        let renderer = player
+            .renderer_mut()
+            .downcast_mut::<WgpuRenderBackend<MovieView>>()
+            .expect("Renderer must be correct type");
+        let image = match renderer.target().capture(&self.descriptors) {
+            Ok(image) => image,
+            Err(e) => {
+                self.gui.screenshot_taken(Err(e));
+                self.window.request_redraw();
+                return;
+            }
+        };
+        let file_name = chrono::Local::now()
+            .format("ruffle-%Y-%m-%d-%H%M%S.png")
+            .to_string();
+        let directory = self.last_screenshot_directory.as_deref();
+        let Some(path) = pick_screenshot_path(&file_name, directory) else {
+            return;
+        };
+        self.last_screenshot_directory = path.parent().map(Path::to_path_buf);
+        let result = image.save(&path).map(|()| path).map_err(anyhow::Error::from);
+        self.gui.screenshot_taken(result);
+        self.window.request_redraw();
+    }
+
+    /// Copies the last rendered frame of the movie to the system clipboard, as an image.
+    pub fn copy_frame(&mut self, player: &mut Player) {
+        let renderer = player
+            .renderer_mut()
+            .downcast_mut::<WgpuRenderBackend<MovieView>>()
+            .expect("Renderer must be correct type");
+        let result = renderer
+            .target()
+            .capture(&self.descriptors)
+            .and_then(|image| {
+                let image = arboard::ImageData {
+                    width: image.width() as usize,
+                    height: image.height() as usize,
+                    bytes: image.into_raw().into(),
+                };
+                arboard::Clipboard::new()
+                    .and_then(|mut clipboard| clipboard.set_image(image))
+                    .map_err(anyhow::Error::from)
+            });
+        self.gui.frame_copied(result);
+        self.window.request_redraw();
+    }
+
+    /// Starts saving `count` frames of the movie as numbered PNGs in `dir`, beginning with the current one.
+    /// The movie is paused and stepped once per redraw instead, so that no frame is skipped however long saving takes.
+    pub fn record_frames(&mut self, player: &mut Player, count: u32, dir: PathBuf) {
+        if let Some(recording) = self.recording.take() {
+            self.finish_recording(recording, Some(player), Ok(()));
+        }
+        let was_playing = player.is_playing();
+        player.pause();
+        self.recording = Some(Recording {
+            dir,
+            count,
+            saved: 0,
+            was_playing,
+        });
+        self.gui.set_recording_progress(Some((0, count)));
+        self.window.request_redraw();
+    }
+
+    /// Stops recording frames early, reporting how many were saved.
+    pub fn stop_recording(&mut self, player: Option<&mut Player>, result: anyhow::Result<()>) {
+        if let Some(recording) = self.recording.take() {
+            self.finish_recording(recording, player, result);
+        }
+    }
+
+    /// Saves the frame that was just rendered and steps the movie to the next one.
+    fn record_frame(&mut self, player: Option<&mut Player>) {
+        let Some(mut recording) = self.recording.take() else {
+            return;
+        };
+        let Some(player) = player else {
+            self.finish_recording(recording, None, Err(anyhow!("The movie was closed")));
+            return;
+        };
+        let path = recording
+            .dir
+            .join(format!("frame-{:05}.png", recording.saved + 1));
+        let renderer = player
+            .renderer_mut()
+            .downcast_mut::<WgpuRenderBackend<MovieView>>()
+            .expect("Renderer must be correct type");
+        let result = renderer
+            .target()
+            .capture(&self.descriptors)
+            .and_then(|image| image.save(&path).map_err(anyhow::Error::from));
+        if let Err(e) = result {
+            self.finish_recording(recording, Some(player), Err(e));
+            return;
+        }
+        recording.saved += 1;
+        if recording.saved == recording.count {
+            self.finish_recording(recording, Some(player), Ok(()));
+            return;
+        }
+        player.step_forward();
+        self.gui
+            .set_recording_progress(Some((recording.saved, recording.count)));
+        self.recording = Some(recording);
+        self.window.request_redraw();
+    }
+
+    fn finish_recording(
+        &mut self,
+        recording: Recording,
+        player: Option<&mut Player>,
+        result: anyhow::Result<()>,
+    ) {
+        if let Some(player) = player {
+            if recording.was_playing {
+                player.play();
+            }
+        }
+        self.gui.set_recording_progress(None);
+        self.gui
+            .recording_finished(result.map(|()| (recording.saved, recording.dir)));
+        self.window.request_redraw();
+    }
+
+    /// Carries out an Edit menu action, moving text between the movie and the system clipboard.
+    pub fn clipboard_op(&mut self, player: &mut Player, command: ClipboardCommand) {
+        let result = match command {
+            ClipboardCommand::Cut | ClipboardCommand::Copy => {
+                match player.copy_selection(command == ClipboardCommand::Cut) {
+                    Some(text) => arboard::Clipboard::new()
+                        .and_then(|mut clipboard| clipboard.set_text(text)),
+                    None => Ok(()),
+                }
+            }
+            ClipboardCommand::Paste => arboard::Clipboard::new()
+                .and_then(|mut clipboard| clipboard.get_text())
+                .map(|text| player.paste(&text.replace("\r\n", "\n"))),
+            ClipboardCommand::SelectAll => {
+                player.select_all();
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            tracing::warn!("Couldn't access the clipboard: {e}");
+        }
+        self.window.request_redraw();
+    }
+
+    /// Saves the given console output to a text file, asking the user where to put it.
+    pub fn save_console_log(&mut self, text: &str) {
+        let Some(path) = pick_console_log_path() else {
+            return;
+        };
+        let result = std::fs::write(&path, text)
+            .map(|()| path)
+            .map_err(anyhow::Error::from);
+        self.gui.console_log_saved(result);
+        self.window.request_redraw();
+    }
+
+    pub fn set_movie_metadata(&mut self, metadata: Option<MovieMetadata>) {
+        self.gui.set_movie_metadata(metadata);
+        self.window.request_redraw();
+    }
+
+    pub fn set_recording_dir(&mut self, dir: PathBuf) {
+        self.gui.set_recording_dir(dir);
+        self.window.request_redraw();
+    }
+
+    pub fn send_playback_command(&mut self, command: PlaybackCommand) {
+        self.gui.send_playback_command(command);
+    }
+
+    pub fn show_preferences(&mut self) {
+        self.gui.show_preferences();
+        self.window.request_redraw();
+    }
+
+    pub fn set_movie_url(&mut self, url: Option<Url>) {
+        self.gui.set_movie_url(url);
+        self.update_file_watcher();
+    }
+
+    pub fn set_watch_file(&mut self, watch_file: bool) {
+        self.watch_file = watch_file;
+        self.update_file_watcher();
+    }
+
+    /// Starts watching the file of the loaded movie if it should be watched, or stops watching it otherwise.
+    /// Movies loaded from a URL aren't watched.
+    fn update_file_watcher(&mut self) {
+        self.file_watcher = None;
+        self.watched_file_changed_at = None;
+        if !self.watch_file {
+            return;
+        }
+        let Some(path) = self.gui.movie_url().and_then(|url| url.to_file_path().ok()) else {
+            return;
+        };
+        match watch_file(&path, self.event_loop.clone()) {
+            Ok(watcher) => self.file_watcher = Some(watcher),
+            Err(e) => tracing::warn!("Couldn't watch {}: {e}", path.display()),
+        }
+    }
+
+    pub fn watched_file_changed(&mut self) {
+        if self.file_watcher.is_some() {
+            self.watched_file_changed_at = Some(Instant::now());
+        }
+    }
+
+    /// When the watched file should be reloaded, once it has stopped changing.
+    pub fn next_watched_file_reload(&self) -> Option<Instant> {
+        Some(self.watched_file_changed_at? + WATCHED_FILE_RELOAD_DELAY)
+    }
+
+    /// Reloads the movie if its file changed and has stayed unchanged for long enough since.
+    pub fn reload_changed_file(&mut self) {
+        if self
+            .next_watched_file_reload()
+            .map_or(false, |reload_at| reload_at <= Instant::now())
+        {
+            self.watched_file_changed_at = None;
+            self.gui.dispatch(RuffleEvent::Reload);
+            self.gui.watched_file_reloaded();
+            self.window.request_redraw();
+        }
+    }
+
+    #[cfg(feature = "gamepad")]
+    pub fn set_gamepad_mapping(&mut self, mapping: GamepadMapping) {
+        self.gamepad_mapping = mapping;
+    }
+
+    /// Presses the keys that gamepad buttons are mapped to, as if on the keyboard, and notices gamepads being
+    /// plugged in or out.
+    #[cfg(feature = "gamepad")]
+    pub fn poll_gamepads(&mut self) {
+        use gilrs::EventType;
+
+        let Some(gilrs) = &mut self.gilrs else {
+            return;
+        };
+        while let Some(gilrs::Event { id, event, .. }) = gilrs.next_event() {
+            match event {
+                EventType::Connected => {
+                    let name = gilrs.gamepad(id).name().to_string();
+                    self.gui.dispatch(RuffleEvent::GamepadConnected(name));
+                }
+                EventType::Disconnected => {
+                    let name = gilrs.gamepad(id).name().to_string();
+                    self.gui.dispatch(RuffleEvent::GamepadDisconnected(name));
+                }
+                EventType::ButtonPressed(button, _) | EventType::ButtonReleased(button, _) => {
+                    let key = GamepadButton::from_gilrs(button)
+                        .and_then(|button| self.gamepad_mapping.get(button));
+                    if let Some(key) = key {
+                        self.gamepad_key_events.push(egui::Event::Key {
+                            key,
+                            pressed: matches!(event, EventType::ButtonPressed(..)),
+                            repeat: false,
+                            modifiers: egui::Modifiers::NONE,
+                        });
+                        self.window.request_redraw();
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// When gamepads should be checked on next, as they don't wake the event loop up by themselves.
+    #[cfg(feature = "gamepad")]
+    pub fn next_gamepad_poll(&self) -> Option<Instant> {
+        let gilrs = self.gilrs.as_ref()?;
+        let interval = if gilrs.gamepads().next().is_some() {
+            GAMEPAD_POLL_INTERVAL
+        } else {
+            GAMEPAD_CONNECT_POLL_INTERVAL
+        };
+        Some(Instant::now() + interval)
+    }
+
+    #[cfg(feature = "gamepad")]
+    pub fn gamepad_connected(&mut self, name: String, is_connected: bool) {
+        self.gui.gamepad_connected(name, is_connected);
+        self.window.request_redraw();
+    }
+
+    pub fn check_for_updates(&mut self, is_automatic: bool) {
+        self.gui.check_for_updates(is_automatic);
+    }
+
+    pub fn update_check_finished(&mut self, result: anyhow::Result<Option<Release>>) {
+        self.gui.update_check_finished(result);
+        self.window.request_redraw();
+    }
+
+    pub fn add_warning(&mut self, message: String) {
+        self.gui.add_warning(message);
+        self.window.request_redraw();
+    }
+
+    pub fn show_load_error(&mut self, source: String, detail: String) {
+        self.gui.show_load_error(source, detail);
+        self.window.request_redraw();
     }
 
-    /*pub fn show_context_menu(&mut self, menu: Vec<ruffle_core::ContextMenuItem>) {
+    pub fn show_context_menu(&mut self, menu: Vec<ContextMenuItem>) {
         self.gui.show_context_menu(menu);
     }
 
     pub fn is_context_menu_visible(&self) -> bool {
         self.gui.is_context_menu_visible()
-    }*/
+    }
+
+    pub fn set_volume(&mut self, volume: f32, is_muted: bool) {
+        self.gui.set_volume(volume, is_muted);
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.gui.is_muted()
+    }
+
+    pub fn set_movie_settings(&mut self, quality: StageQuality, scale_mode: ScaleMode, zoom: f32) {
+        self.gui.set_movie_settings(quality, scale_mode, zoom);
+    }
+
+    /// Enters or leaves kiosk mode, where the movie fills the screen without any of Ruffle's UI.
+    /// Leaving it puts the window back the way it was, fullscreen or not.
+    pub fn set_kiosk(&mut self, kiosk: bool) {
+        if kiosk == self.gui.is_kiosk() {
+            return;
+        }
+        self.gui.set_kiosk(kiosk);
+        if kiosk {
+            self.fullscreen_before_kiosk = self.window.fullscreen();
+            self.window
+                .set_fullscreen(Some(Fullscreen::Borderless(None)));
+        } else {
+            let fullscreen = self.fullscreen_before_kiosk.take();
+            let is_fullscreen = fullscreen.is_some();
+            self.window.set_fullscreen(fullscreen);
+            if !is_fullscreen {
+                self.restore_window_level();
+            }
+        }
+        self.window.request_redraw();
+    }
+
+    pub fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.always_on_top = always_on_top;
+        self.restore_window_level();
+    }
+
+    /// Puts the window back above or among other windows, as chosen by the user.
+    /// Some platforms forget this while the window is fullscreen, so it's done again after leaving fullscreen.
+    pub fn restore_window_level(&self) {
+        self.window.set_window_level(if self.always_on_top {
+            WindowLevel::AlwaysOnTop
+        } else {
+            WindowLevel::Normal
+        });
+    }
+
+    pub fn set_forward_mouse_wheel(&mut self, forward_mouse_wheel: bool) {
+        self.forward_mouse_wheel = forward_mouse_wheel;
+        self.wheel_zoom_lines = 0.0;
+    }
+
+    /// Sends scrolling to the movie, or zooms the view with it if the movie shouldn't get it or has no use for it.
+    /// Scrolling with Cmd/Ctrl held always zooms, like it does in browsers.
+    /// Trackpads scroll by pixels rather than by lines, so those are converted to lines to scroll as far either way.
+    pub fn mouse_wheel(
+        &mut self,
+        delta: MouseScrollDelta,
+        is_command_down: bool,
+        player: Option<&mut Player>,
+    ) {
+        let lines = match delta {
+            MouseScrollDelta::LineDelta(_, y) => y as f64,
+            MouseScrollDelta::PixelDelta(position) => {
+                position.y / (PIXELS_PER_SCROLL_LINE * self.window.scale_factor())
+            }
+        };
+        if self.forward_mouse_wheel && !is_command_down {
+            if let Some(player) = player {
+                if player.mouse_wheel(lines) {
+                    self.wheel_zoom_lines = 0.0;
+                    self.window.request_redraw();
+                    return;
+                }
+            }
+        }
+        // Trackpads scroll a little at a time, so only step the zoom once a whole line has been scrolled.
+        self.wheel_zoom_lines += lines;
+        while self.wheel_zoom_lines >= 1.0 {
+            self.gui.zoom_in();
+            self.wheel_zoom_lines -= 1.0;
+        }
+        while self.wheel_zoom_lines <= -1.0 {
+            self.gui.zoom_out();
+            self.wheel_zoom_lines += 1.0;
+        }
+    }
+
+    /// Whether one of our own text fields has keyboard focus, or a shortcut is being rebound.
+    pub fn wants_keyboard_input(&self) -> bool {
+        self.egui_ctx.wants_keyboard_input() || self.gui.is_rebinding_shortcut()
+    }
+
+    pub fn is_kiosk(&self) -> bool {
+        self.gui.is_kiosk()
+    }
+
+    pub fn set_system_theme(&mut self, theme: winit::window::Theme) {
+        self.gui.set_system_theme(Some(theme));
+        self.window.request_redraw();
+    }
+
+    pub fn set_system_reduces_motion(&mut self, system_reduces_motion: bool) {
+        self.gui.set_system_reduces_motion(system_reduces_motion);
+        self.window.request_redraw();
+    }
+
+    pub fn set_play_overlay(&mut self, show_play_overlay: bool) {
+        self.gui.set_play_overlay(show_play_overlay);
+        self.window.request_redraw();
+    }
+
+    pub fn set_defaults(&mut self, volume: f32, quality: StageQuality) {
+        self.gui.set_defaults(volume, quality);
+        self.window.request_redraw();
+    }
+
+    pub fn set_quality(&mut self, quality: StageQuality) {
+        self.gui.set_quality(quality);
+    }
+
+    pub fn add_recent_file(&mut self, path: PathBuf) {
+        self.gui.add_recent_file(path);
+    }
+
+    pub fn remove_recent_file(&mut self, path: &Path) {
+        self.gui.remove_recent_file(path);
+    }
+
+    pub fn add_to_playlist(&mut self, urls: Vec<Url>) {
+        self.gui.add_to_playlist(urls);
+        self.window.request_redraw();
+    }
+
+    pub fn movie_playback_ended(&mut self) {
+        self.gui.movie_playback_ended();
+        self.window.request_redraw();
+    }
+
+    pub fn set_max_fps(&mut self, max_fps: MaxFps) {
+        self.max_fps = max_fps;
+        self.window.request_redraw();
+    }
+
+    /// When the next frame may be rendered without going over the frame rate limit,
+    /// or `None` if it may be rendered right away.
+    pub fn throttled_until(&self) -> Option<Instant> {
+        let display_refresh_rate = self
+            .window
+            .current_monitor()
+            .and_then(|monitor| monitor.refresh_rate_millihertz())
+            .map(|millihertz| (millihertz + 500) / 1000);
+        let max_fps = self.max_fps.limit(display_refresh_rate).filter(|&fps| fps > 0)?;
+        let due_at = self.last_frame_at? + Duration::from_secs_f64(1.0 / max_fps as f64);
+        (due_at > Instant::now()).then_some(due_at)
+    }
 
     pub fn needs_render(&self) -> bool {
         Instant::now().duration_since(self.last_update) >= self.repaint_after
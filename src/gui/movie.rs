@@ -1,4 +1,3 @@
-use crate::gui::MENU_HEIGHT;
 use ruffle_render_wgpu::descriptors::Descriptors;
 use ruffle_render_wgpu::target::{RenderTarget, RenderTargetFrame};
 use std::borrow::Cow;
@@ -13,20 +12,17 @@ pub struct MovieViewRenderer {
     vertices: wgpu::Buffer,
 }
 
-fn get_vertices(has_menu: bool, height: u32) -> [[f32; 4]; 6] {
-    let top = if has_menu {
-        1.0 - ((MENU_HEIGHT as f32 / height as f32) * 2.0)
-    } else {
-        1.0
-    };
+fn get_vertices(menu_height: u32, status_bar_height: u32, height: u32) -> [[f32; 4]; 6] {
+    let top = 1.0 - ((menu_height as f32 / height as f32) * 2.0);
+    let bottom = -1.0 + ((status_bar_height as f32 / height as f32) * 2.0);
     // x y u v
     [
-        [-1.0, top, 0.0, 0.0],  // tl
-        [1.0, top, 1.0, 0.0],   // tr
-        [1.0, -1.0, 1.0, 1.0],  // br
-        [1.0, -1.0, 1.0, 1.0],  // br
-        [-1.0, -1.0, 0.0, 1.0], // bl
-        [-1.0, top, 0.0, 0.0],  // tl
+        [-1.0, top, 0.0, 0.0],    // tl
+        [1.0, top, 1.0, 0.0],     // tr
+        [1.0, bottom, 1.0, 1.0],  // br
+        [1.0, bottom, 1.0, 1.0],  // br
+        [-1.0, bottom, 0.0, 1.0], // bl
+        [-1.0, top, 0.0, 0.0],    // tl
     ]
 }
 
@@ -34,7 +30,8 @@ impl MovieViewRenderer {
     pub fn new(
         device: &wgpu::Device,
         surface_format: wgpu::TextureFormat,
-        has_menu: bool,
+        menu_height: u32,
+        status_bar_height: u32,
         height: u32,
     ) -> Self {
         let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
@@ -119,7 +116,7 @@ impl MovieViewRenderer {
         });
         let vertices = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: None,
-            contents: bytemuck::cast_slice(&get_vertices(has_menu, height)),
+            contents: bytemuck::cast_slice(&get_vertices(menu_height, status_bar_height, height)),
             usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
         });
 
@@ -131,11 +128,17 @@ impl MovieViewRenderer {
         }
     }
 
-    pub fn update_resolution(&self, descriptors: &Descriptors, has_menu: bool, height: u32) {
+    pub fn update_resolution(
+        &self,
+        descriptors: &Descriptors,
+        menu_height: u32,
+        status_bar_height: u32,
+        height: u32,
+    ) {
         descriptors.queue.write_buffer(
             &self.vertices,
             0,
-            bytemuck::cast_slice(&get_vertices(has_menu, height)),
+            bytemuck::cast_slice(&get_vertices(menu_height, status_bar_height, height)),
         );
     }
 }
@@ -165,7 +168,9 @@ impl MovieView {
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
             format: wgpu::TextureFormat::Rgba8Unorm,
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC,
             view_formats: &[],
         });
         let view = texture.create_view(&Default::default());
@@ -190,6 +195,66 @@ impl MovieView {
         }
     }
 
+    /// Reads back the last rendered frame from the GPU.
+    pub fn capture(&self, descriptors: &Descriptors) -> anyhow::Result<image::RgbaImage> {
+        let width = self.texture.width();
+        let height = self.texture.height();
+        // Rows copied out of a texture must be padded to a fixed alignment.
+        let unpadded_bytes_per_row = width * 4;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = (unpadded_bytes_per_row + align - 1) / align * align;
+        let buffer = descriptors.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("screenshot buffer"),
+            size: padded_bytes_per_row as u64 * height as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder =
+            descriptors
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("screenshot encoder"),
+                });
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+        descriptors.queue.submit(Some(encoder.finish()));
+
+        let slice = buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        descriptors.device.poll(wgpu::Maintain::Wait);
+        receiver.recv()??;
+
+        let mut pixels = Vec::with_capacity((unpadded_bytes_per_row * height) as usize);
+        for row in slice.get_mapped_range().chunks(padded_bytes_per_row as usize) {
+            pixels.extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
+        }
+        buffer.unmap();
+        image::RgbaImage::from_raw(width, height, pixels)
+            .ok_or_else(|| anyhow::anyhow!("Captured frame has an unexpected size"))
+    }
+
     pub fn render<'pass, 'global: 'pass>(
         &'pass self,
         renderer: &'global MovieViewRenderer,
@@ -8,11 +8,16 @@
 
 mod app;
 mod cli;
+mod console;
 mod custom_event;
 mod executor;
+mod gamepad;
 mod gui;
 mod player;
+mod preferences;
+mod shortcuts;
 mod task;
+mod update;
 mod util;
 mod editor;
 
@@ -20,8 +25,11 @@ use anyhow::Error;
 use app::App;
 use clap::Parser;
 use cli::Opt;
+use console::{ConsoleLayer, LogBuffer};
 use std::cell::RefCell;
+use std::fs::File;
 use std::panic::PanicInfo;
+use std::sync::Mutex;
 use url::Url;
 
 thread_local! {
@@ -45,7 +53,9 @@ static RUFFLE_VERSION: &str = concat!(
     ")"
 );
 
-fn init() {
+/// Sets up logging to stderr, filtered by `RUST_LOG`, to a log file for this session at info level,
+/// and to the console in the GUI, which gets everything down to debug level through the returned buffer.
+fn init() -> LogBuffer {
     // When linked with the windows subsystem windows won't automatically attach
     // to the console of the parent process, so we do it explicitly. This fails
     // silently if the parent has no console.
@@ -61,16 +71,53 @@ fn init() {
         panic_hook(info);
     }));
 
-    let subscriber = tracing_subscriber::fmt::Subscriber::builder()
-        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
-        .finish();
+    use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::Layer;
+    let log_buffer = LogBuffer::default();
+    let log_file = create_log_file().map(|file| {
+        tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(Mutex::new(file))
+            .with_filter(LevelFilter::INFO)
+    });
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(EnvFilter::from_default_env()))
+        .with(log_file)
+        // Debug output is plentiful enough to slow everything down, so it's only collected when asked for.
+        .with(
+            ConsoleLayer::new(log_buffer.clone()).with_filter(
+                EnvFilter::builder()
+                    .with_default_directive(LevelFilter::INFO.into())
+                    .from_env_lossy(),
+            ),
+        );
     #[cfg(feature = "tracy")]
     let subscriber = {
-        use tracing_subscriber::layer::SubscriberExt;
         let tracy_subscriber = tracing_tracy::TracyLayer::new();
         subscriber.with(tracy_subscriber)
     };
     tracing::subscriber::set_global_default(subscriber).expect("Couldn't set up global subscriber");
+    log_buffer
+}
+
+/// Starts the log file over, so that it only holds what happened since this launch.
+/// Logging isn't set up yet, so failures can only go to stderr.
+fn create_log_file() -> Option<File> {
+    let path = util::log_file()?;
+    if let Some(dir) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            eprintln!("Couldn't create {}: {e}", dir.display());
+            return None;
+        }
+    }
+    match File::create(&path) {
+        Ok(file) => Some(file),
+        Err(e) => {
+            eprintln!("Couldn't create log file {}: {e}", path.display());
+            None
+        }
+    }
 }
 
 fn panic_hook(info: &PanicInfo) {
@@ -135,9 +182,9 @@ fn shutdown() {
 }
 
 fn main() -> Result<(), Error> {
-    init();
+    let log_buffer = init();
     let opt = Opt::parse();
-    let result = App::new(opt).map(|app| app.run());
+    let result = App::new(opt, log_buffer).map(|app| app.run());
     #[cfg(windows)]
     if let Err(error) = &result {
         eprintln!("{:?}", error)
@@ -3,21 +3,27 @@ use crate::custom_event::RuffleEvent;
 use crate::executor::GlutinAsyncExecutor;
 use crate::gui::MovieView;
 use crate::{RENDER_INFO, SWF_INFO};
-use crate::editor::player::Player;
-use anyhow::anyhow;
+use crate::editor::main::ScaleMode;
+use crate::editor::player::{Letterbox, MovieMetadata, Player};
+use anyhow::{anyhow, Context};
 use ruffle_render::backend::RenderBackend;
+use ruffle_render::quality::StageQuality;
 use ruffle_render_wgpu::backend::WgpuRenderBackend;
 use ruffle_render_wgpu::descriptors::Descriptors;
-use std::rc::Rc;
+use swf::Color;
 use std::sync::{Arc, Mutex, MutexGuard};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use url::Url;
 use winit::event_loop::EventLoopProxy;
-use winit::window::Window;
 
 struct ActivePlayer {
     player: Arc<Mutex<Player>>,    
     executor: Arc<Mutex<GlutinAsyncExecutor>>,
+    /// Where the movie was loaded from, so that it can be reloaded.
+    /// Movies that were given to us in memory don't have one, and can't be reloaded.
+    movie_url: Option<Url>,
+    /// Parameters given to the movie on top of the ones in its URL, so that they can be given again on reload.
+    parameters: Vec<(String, String)>,
 }
 
 impl ActivePlayer {
@@ -25,10 +31,10 @@ impl ActivePlayer {
         opt: &Opt,
         event_loop: EventLoopProxy<RuffleEvent>,
         movie_url: Url,
-        window: Rc<Window>,
+        parameters: Vec<(String, String)>,
         descriptors: Arc<Descriptors>,
         movie_view: MovieView,
-    ) -> Self {
+    ) -> anyhow::Result<Self> {
         /*let mut builder = PlayerBuilder::new();
 
         match CpalAudioBackend::new() {
@@ -56,9 +62,7 @@ impl ActivePlayer {
                 builder.with_video(ruffle_video_software::backend::SoftwareVideoBackend::new());
         }*/
 
-        let renderer = WgpuRenderBackend::new(descriptors, movie_view)
-            .map_err(|e| anyhow!(e.to_string()))
-            .expect("Couldn't create wgpu rendering backend");
+        let renderer = Self::create_renderer(descriptors, movie_view)?;
         /*RENDER_INFO.with(|i| *i.borrow_mut() = Some(renderer.debug_info().to_string()));
 
         builder = builder
@@ -79,13 +83,17 @@ impl ActivePlayer {
             .with_frame_rate(opt.frame_rate);
         let player = builder.build();*/
 
-        let name = movie_url
-            .path_segments()
-            .and_then(|segments| segments.last())
-            .unwrap_or_else(|| movie_url.as_str())
-            .to_string();
+        let path = movie_url
+            .to_file_path()
+            .map_err(|()| anyhow!("Only local project files can be opened, not {movie_url}"))?;
+        let size = std::fs::metadata(&path).ok().map(|metadata| metadata.len());
+        let mut player = Player::new(Box::new(renderer), path)?;
+        let mut movie_parameters: Vec<(String, String)> =
+            movie_url.query_pairs().into_owned().collect();
+        movie_parameters.extend(parameters.iter().cloned());
+        player.set_parameters(movie_parameters);
 
-        window.set_title(&format!("Ruffle - {name}"));
+        let name = movie_name(&movie_url);
 
         /*SWF_INFO.with(|i| *i.borrow_mut() = Some(name.clone()));
 
@@ -103,47 +111,239 @@ impl ActivePlayer {
             });
             player_lock.fetch_root_movie(movie_url.to_string(), parameters, Box::new(on_metadata));
         }*/
-        
-        let player = Arc::new(Mutex::new(Player::new(Box::new(renderer), movie_url.to_file_path().expect("Invalid movie path"))));
 
-        Self { player, executor }
+        Ok(Self::start(
+            player,
+            name,
+            size,
+            event_loop,
+            executor,
+            Some(movie_url),
+            parameters,
+        ))
+    }
+
+    /// Plays a movie that's already in memory, such as one piped in through standard input, under the given name.
+    pub fn from_bytes(
+        event_loop: EventLoopProxy<RuffleEvent>,
+        name: String,
+        data: &[u8],
+        descriptors: Arc<Descriptors>,
+        movie_view: MovieView,
+    ) -> anyhow::Result<Self> {
+        let executor = GlutinAsyncExecutor::new(event_loop.clone());
+        let renderer = Self::create_renderer(descriptors, movie_view)?;
+        let player = Player::from_bytes(Box::new(renderer), data)?;
+        let size = data.len() as u64;
+        Ok(Self::start(
+            player,
+            name,
+            Some(size),
+            event_loop,
+            executor,
+            None,
+            vec![],
+        ))
+    }
+
+    fn create_renderer(
+        descriptors: Arc<Descriptors>,
+        movie_view: MovieView,
+    ) -> anyhow::Result<WgpuRenderBackend<MovieView>> {
+        WgpuRenderBackend::new(descriptors, movie_view)
+            .map_err(|e| anyhow!(e.to_string()))
+            .context("Couldn't create wgpu rendering backend")
+    }
+
+    /// Lets the GUI know about the loaded movie, under the given name unless it has a title of its own.
+    fn start(
+        player: Player,
+        name: String,
+        file_size: Option<u64>,
+        event_loop: EventLoopProxy<RuffleEvent>,
+        executor: Arc<Mutex<GlutinAsyncExecutor>>,
+        movie_url: Option<Url>,
+        parameters: Vec<(String, String)>,
+    ) -> Self {
+        // Prefer the title the movie gives itself, if it has one.
+        let title = player.title().map(str::to_owned).unwrap_or(name);
+        let _ = event_loop.send_event(RuffleEvent::MovieTitleChanged(Some(title)));
+        let metadata = MovieMetadata {
+            file_size,
+            ..player.metadata()
+        };
+        let _ = event_loop.send_event(RuffleEvent::MovieMetadata(metadata));
+        for warning in player.unsupported_content() {
+            tracing::warn!("{warning}");
+            let _ = event_loop.send_event(RuffleEvent::Warning(warning));
+        }
+
+        Self {
+            player: Arc::new(Mutex::new(player)),
+            executor,
+            movie_url,
+            parameters,
+        }
     }
 }
 
 pub struct PlayerController {
     player: Option<ActivePlayer>,
+    volume: f32,
+    quality: StageQuality,
+    scale_mode: ScaleMode,
+    force_scale_mode: bool,
+    zoom: f32,
+    letterbox: Letterbox,
+    letterbox_color: Color,
     event_loop: EventLoopProxy<RuffleEvent>,
-    window: Rc<Window>,
     descriptors: Arc<Descriptors>,
 }
 
 impl PlayerController {
-    pub fn new(
-        event_loop: EventLoopProxy<RuffleEvent>,
-        window: Rc<Window>,
-        descriptors: Arc<Descriptors>,
-    ) -> Self {
+    pub fn new(event_loop: EventLoopProxy<RuffleEvent>, descriptors: Arc<Descriptors>) -> Self {
         Self {
             player: None,
+            volume: 1.0,
+            quality: StageQuality::High,
+            scale_mode: ScaleMode::default(),
+            force_scale_mode: false,
+            zoom: 1.0,
+            letterbox: Letterbox::default(),
+            letterbox_color: Color { r: 0, g: 0, b: 0, a: 255 },
             event_loop,
-            window,
             descriptors,
         }
     }
 
-    pub fn create(&mut self, opt: &Opt, movie_url: Url, movie_view: MovieView) {
-        self.player = Some(ActivePlayer::new(
+    pub fn create(
+        &mut self,
+        opt: &Opt,
+        movie_url: Url,
+        parameters: Vec<(String, String)>,
+        movie_view: MovieView,
+    ) -> anyhow::Result<()> {
+        let player = ActivePlayer::new(
             opt,
             self.event_loop.clone(),
             movie_url,
-            self.window.clone(),
+            parameters,
+            self.descriptors.clone(),
+            movie_view,
+        )?;
+        self.start(player);
+        Ok(())
+    }
+
+    /// Plays a movie that's already in memory, under the given name.
+    pub fn create_from_bytes(
+        &mut self,
+        name: String,
+        data: &[u8],
+        movie_view: MovieView,
+    ) -> anyhow::Result<()> {
+        let player = ActivePlayer::from_bytes(
+            self.event_loop.clone(),
+            name,
+            data,
             self.descriptors.clone(),
             movie_view,
-        ));
+        )?;
+        self.start(player);
+        Ok(())
+    }
+
+    fn start(&mut self, player: ActivePlayer) {
+        self.player = Some(player);
+        if let Some(mut player) = self.get() {
+            player.set_volume(self.volume);
+            player.set_quality(self.quality);
+            player.set_scale_mode(self.scale_mode);
+            player.set_force_scale_mode(self.force_scale_mode);
+            player.set_zoom(self.zoom);
+            player.set_letterbox(self.letterbox);
+            player.set_letterbox_color(self.letterbox_color);
+        }
+    }
+
+    /// Sets the volume of the current player, and of any player created afterwards.
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume;
+        if let Some(mut player) = self.get() {
+            player.set_volume(volume);
+        }
+    }
+
+    /// Sets the rendering quality of the current player, and of any player created afterwards.
+    pub fn set_quality(&mut self, quality: StageQuality) {
+        self.quality = quality;
+        if let Some(mut player) = self.get() {
+            player.set_quality(quality);
+        }
+    }
+
+    /// Sets the scale mode of the current player, and of any player created afterwards.
+    pub fn set_scale_mode(&mut self, scale_mode: ScaleMode) {
+        self.scale_mode = scale_mode;
+        if let Some(mut player) = self.get() {
+            player.set_scale_mode(scale_mode);
+        }
+    }
+
+    /// Sets whether the scale mode overrides the movie's own, for the current player and any created afterwards.
+    pub fn set_force_scale_mode(&mut self, force_scale_mode: bool) {
+        self.force_scale_mode = force_scale_mode;
+        if let Some(mut player) = self.get() {
+            player.set_force_scale_mode(force_scale_mode);
+        }
+    }
+
+    /// Sets the zoom of the current player, and of any player created afterwards.
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom;
+        if let Some(mut player) = self.get() {
+            player.set_zoom(zoom);
+        }
+    }
+
+    /// Sets the letterbox mode of the current player, and of any player created afterwards.
+    pub fn set_letterbox(&mut self, letterbox: Letterbox) {
+        self.letterbox = letterbox;
+        if let Some(mut player) = self.get() {
+            player.set_letterbox(letterbox);
+        }
+    }
+
+    /// Sets the letterbox color of the current player, and of any player created afterwards.
+    pub fn set_letterbox_color(&mut self, color: Color) {
+        self.letterbox_color = color;
+        if let Some(mut player) = self.get() {
+            player.set_letterbox_color(color);
+        }
+    }
+
+    /// Where the current movie was loaded from, if there is one.
+    pub fn movie_url(&self) -> Option<&Url> {
+        self.player.as_ref().and_then(|player| player.movie_url.as_ref())
+    }
+
+    /// The parameters that the current movie was opened with, on top of the ones in its URL.
+    pub fn movie_parameters(&self) -> &[(String, String)] {
+        match &self.player {
+            Some(player) => &player.parameters,
+            None => &[],
+        }
+    }
+
+    pub fn quality(&self) -> StageQuality {
+        self.quality
     }
 
     pub fn destroy(&mut self) {
         self.player = None;
+        let _ = self
+            .event_loop
+            .send_event(RuffleEvent::MovieTitleChanged(None));
     }
 
     pub fn get(&self) -> Option<MutexGuard<Player>> {
@@ -159,6 +359,25 @@ impl PlayerController {
         }
     }
 
+    /// Plays the current movie on by `dt`, letting the GUI know if it played to its end.
+    /// Returns whether the movie moved on to another frame, which then needs to be drawn.
+    pub fn tick(&self, dt: Duration) -> bool {
+        let Some(mut player) = self.get() else {
+            return false;
+        };
+        let frame = player.current_frame();
+        if player.tick(dt) {
+            let _ = self.event_loop.send_event(RuffleEvent::MoviePlaybackEnded);
+        }
+        player.current_frame() != frame
+    }
+
+    /// When the current movie's next frame is due, if it's playing.
+    pub fn next_frame_time(&self, now: Instant) -> Option<Instant> {
+        let player = self.get()?;
+        Some(now + player.time_til_next_frame()?)
+    }
+
     pub fn poll(&self) {
         if let Some(player) = &self.player {
             /*player
@@ -169,3 +388,21 @@ impl PlayerController {
         }
     }
 }
+
+/// What a movie is called when it doesn't have a title of its own: its file name, without any escaping,
+/// or the site it's from if its address doesn't end in one.
+fn movie_name(url: &Url) -> String {
+    if let Some(file_name) = url
+        .to_file_path()
+        .ok()
+        .and_then(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()))
+    {
+        return file_name;
+    }
+    url.path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_owned)
+        .or_else(|| url.host_str().map(str::to_owned))
+        .unwrap_or_else(|| url.to_string())
+}
@@ -0,0 +1,391 @@
+//! User preferences that are remembered between sessions
+
+use crate::editor::main::ScaleMode;
+use crate::gamepad::GamepadMapping;
+use crate::shortcuts::Shortcuts;
+use anyhow::{anyhow, Error};
+use ruffle_render::quality::StageQuality;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, TryLockError};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use url::Url;
+
+/// Name of the file, inside the config directory, that preferences are stored in.
+const PREFERENCES_FILE: &str = "preferences.json";
+
+/// Name of the file, inside the config directory, that the instance saving the preferences holds a lock on.
+const LOCK_FILE: &str = "preferences.lock";
+
+/// Whether this instance of the player gets to save the preferences, which is worked out when they're loaded.
+static PREFERENCES_LOCK: OnceLock<PreferencesLock> = OnceLock::new();
+
+enum PreferencesLock {
+    /// This instance holds the lock. The lock file is kept open for as long as it's held,
+    /// and the operating system lets go of the lock when the instance exits, even if it crashes.
+    Held { _file: File },
+    /// Another instance holds the lock.
+    HeldElsewhere,
+    /// There's no lock file to coordinate with, so the preferences are saved as if there were no other instances.
+    Unavailable,
+}
+
+/// The smallest and largest that the GUI can be scaled to.
+pub const UI_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.75..=2.0;
+
+/// Persisted user preferences.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    /// The language chosen by the user, or `None` to follow the system locale.
+    pub language: Option<String>,
+    /// Whether to look for a newer release of Ruffle when it starts.
+    pub check_for_updates: bool,
+    /// The rendering quality that movies are played at.
+    #[serde(with = "stage_quality")]
+    pub quality: StageQuality,
+    /// The audio volume, as a number between 0 and 1, regardless of whether audio is muted.
+    pub volume: f32,
+    pub is_muted: bool,
+    /// Whether to pause the movie while the window is unfocused.
+    pub pause_on_unfocus: bool,
+    /// Whether scrolling goes to the movie, rather than zooming the view, when the movie has a use for it.
+    pub forward_mouse_wheel: bool,
+    /// Whether to hide the menu bar in fullscreen until the pointer reaches the top of the screen.
+    pub auto_hide_menu: bool,
+    /// Whether to hide the menu bar in a window too, until the pointer reaches the top of it.
+    pub hide_menu_bar: bool,
+    /// Whether the window goes fullscreen as soon as Ruffle starts.
+    pub start_fullscreen: bool,
+    /// Whether the window is kept above other windows.
+    pub always_on_top: bool,
+    /// Whether movies start playing as soon as they're loaded.
+    pub autoplay: AutoplayPolicy,
+    /// Whether to ask before exiting while a movie is loaded.
+    pub confirm_exit: bool,
+    /// The color scheme of the GUI.
+    pub theme: Theme,
+    /// How much larger than usual menus and dialogs are drawn. Movies aren't affected.
+    pub ui_scale: f32,
+    /// Whether menus, dialogs and messages appear without animating. Movies aren't affected.
+    pub reduce_motion: ReduceMotion,
+    /// How often the window may be redrawn, to save power.
+    pub max_fps: MaxFps,
+    /// Movies saved by the user to reopen later, in the order they're listed in.
+    pub bookmarks: Vec<Bookmark>,
+    /// Files listed in the "Open Recent" menu, most recent first.
+    pub recent_files: Vec<PathBuf>,
+    /// Addresses entered in the Open URL prompt, most recent first.
+    pub url_history: Vec<String>,
+    /// URLs of movies that the user doesn't want to be warned about anymore.
+    pub silenced_warnings: Vec<String>,
+    /// Settings changed while a movie was loaded, which it's played with the next time it's loaded.
+    pub movie_settings: HashMap<MovieKey, MovieSettings>,
+    /// Keyboard shortcuts for menu actions, as rebound by the user.
+    pub shortcuts: Shortcuts,
+    /// The keys that gamepad buttons press.
+    pub gamepad_mapping: GamepadMapping,
+    /// Where the window was, and how big it was, when Ruffle last exited.
+    pub window_geometry: Option<WindowGeometry>,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            language: None,
+            check_for_updates: false,
+            quality: StageQuality::High,
+            volume: 1.0,
+            is_muted: false,
+            pause_on_unfocus: false,
+            forward_mouse_wheel: true,
+            auto_hide_menu: true,
+            hide_menu_bar: false,
+            start_fullscreen: false,
+            always_on_top: false,
+            autoplay: AutoplayPolicy::On,
+            confirm_exit: false,
+            theme: Theme::System,
+            ui_scale: 1.0,
+            reduce_motion: ReduceMotion::System,
+            max_fps: MaxFps::Off,
+            bookmarks: vec![],
+            recent_files: vec![],
+            url_history: vec![],
+            silenced_warnings: vec![],
+            movie_settings: HashMap::new(),
+            shortcuts: Shortcuts::default(),
+            gamepad_mapping: GamepadMapping::default(),
+            window_geometry: None,
+        }
+    }
+}
+
+/// How a movie starts out when it's loaded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoplayPolicy {
+    /// Start playing right away.
+    #[default]
+    On,
+    /// Start playing right away, but with audio muted until the user unmutes it.
+    Muted,
+    /// Stay paused until the user clicks to play.
+    Off,
+}
+
+/// Color schemes that the GUI can be shown in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+    /// Follow the system's light or dark preference, falling back to dark.
+    #[default]
+    System,
+    Light,
+    Dark,
+    HighContrast,
+}
+
+/// Whether the GUI's animations are turned off.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReduceMotion {
+    /// Follow the system's preference, keeping animations if it can't be told.
+    #[default]
+    System,
+    On,
+    Off,
+}
+
+/// Limits on how often the window is redrawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MaxFps {
+    /// Redraw as often as anything asks for it.
+    #[default]
+    Off,
+    Fps30,
+    Fps60,
+    /// Redraw no more often than the monitor refreshes.
+    MatchDisplay,
+}
+
+impl MaxFps {
+    /// The number of frames per second this allows, given the refresh rate of the monitor the window is on.
+    /// Matching a display of unknown refresh rate doesn't limit anything.
+    pub fn limit(self, display_refresh_rate: Option<u32>) -> Option<u32> {
+        match self {
+            Self::Off => None,
+            Self::Fps30 => Some(30),
+            Self::Fps60 => Some(60),
+            Self::MatchDisplay => display_refresh_rate,
+        }
+    }
+}
+
+/// A movie saved to the Bookmarks menu.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub name: String,
+    /// Where the movie is loaded from, which is a `file:` URL for local movies.
+    pub url: String,
+}
+
+/// Identifies a movie by where it's loaded from, so that its settings are found again when it's reopened.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MovieKey(String);
+
+impl MovieKey {
+    /// The fragment doesn't change which movie is loaded, so it's left out.
+    pub fn new(url: &Url) -> Self {
+        let mut url = url.clone();
+        url.set_fragment(None);
+        Self(url.into())
+    }
+}
+
+/// Settings that a single movie is played with instead of the defaults.
+/// Only the settings that were changed while the movie was loaded are set.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MovieSettings {
+    #[serde(
+        with = "stage_quality::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub quality: Option<StageQuality>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale_mode: Option<ScaleMode>,
+    /// The audio volume, as a number between 0 and 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zoom: Option<f32>,
+}
+
+/// Outer position and inner size of the main window, in physical pixels.
+/// The position is left out on platforms that don't tell where windows are, like Wayland.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<i32>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Preferences {
+    /// Loads the saved preferences, falling back to the defaults if there are none or they can't be read.
+    pub fn load() -> Self {
+        if !may_save() {
+            tracing::info!("Another window is open, so changes to the preferences made in this one won't be saved");
+        }
+        let mut preferences = Self::read();
+        // The file may have been edited by hand.
+        preferences.volume = if preferences.volume.is_nan() {
+            1.0
+        } else {
+            preferences.volume.clamp(0.0, 1.0)
+        };
+        preferences.ui_scale = if preferences.ui_scale.is_nan() {
+            1.0
+        } else {
+            preferences
+                .ui_scale
+                .clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end())
+        };
+        preferences
+    }
+
+    fn read() -> Self {
+        let Some(path) = config_dir().map(|dir| dir.join(PREFERENCES_FILE)) else {
+            return Self::default();
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                tracing::warn!("Couldn't parse preferences at {}: {e}", path.display());
+                Self::default()
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => {
+                tracing::warn!("Couldn't read preferences at {}: {e}", path.display());
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the preferences to disk, unless another instance of the player holds the lock on them.
+    /// That's the instance started first, so windows opened later, such as with "Open in New Window",
+    /// don't overwrite each other's changes. Failures are logged, as there's nothing else to do about them.
+    pub fn save(&self) {
+        if !may_save() {
+            return;
+        }
+        if let Err(e) = self.write() {
+            tracing::warn!("Couldn't save preferences: {e}");
+        }
+    }
+
+    fn write(&self) -> Result<(), Error> {
+        let dir = config_dir().ok_or_else(|| anyhow!("No config directory available"))?;
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(
+            dir.join(PREFERENCES_FILE),
+            serde_json::to_string_pretty(self)?,
+        )?;
+        Ok(())
+    }
+}
+
+/// Whether this instance of the player gets to save the preferences, taking the lock on them if nobody has it yet.
+pub fn may_save() -> bool {
+    let lock = PREFERENCES_LOCK.get_or_init(|| {
+        let Some(dir) = config_dir() else {
+            return PreferencesLock::Unavailable;
+        };
+        let file = std::fs::create_dir_all(&dir).and_then(|()| File::create(dir.join(LOCK_FILE)));
+        match file {
+            Ok(file) => match file.try_lock() {
+                Ok(()) => PreferencesLock::Held { _file: file },
+                Err(TryLockError::WouldBlock) => PreferencesLock::HeldElsewhere,
+                Err(TryLockError::Error(e)) => {
+                    tracing::warn!("Couldn't lock the preferences: {e}");
+                    PreferencesLock::Unavailable
+                }
+            },
+            Err(e) => {
+                tracing::warn!("Couldn't create the preferences lock file: {e}");
+                PreferencesLock::Unavailable
+            }
+        }
+    });
+    !matches!(lock, PreferencesLock::HeldElsewhere)
+}
+
+/// `StageQuality` can't be serialized by itself, so it's stored by name.
+/// Only the qualities offered in the GUI are supported, anything else is read back as high quality.
+mod stage_quality {
+    use ruffle_render::quality::StageQuality;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        quality: &StageQuality,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(name(*quality))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<StageQuality, D::Error> {
+        Ok(from_name(&String::deserialize(deserializer)?))
+    }
+
+    fn name(quality: StageQuality) -> &'static str {
+        match quality {
+            StageQuality::Low => "low",
+            StageQuality::Medium => "medium",
+            StageQuality::Best => "best",
+            _ => "high",
+        }
+    }
+
+    fn from_name(name: &str) -> StageQuality {
+        match name {
+            "low" => StageQuality::Low,
+            "medium" => StageQuality::Medium,
+            "best" => StageQuality::Best,
+            _ => StageQuality::High,
+        }
+    }
+
+    /// The same, for a quality that may not be set.
+    pub mod option {
+        use ruffle_render::quality::StageQuality;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            quality: &Option<StageQuality>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match quality {
+                Some(quality) => serializer.serialize_some(super::name(*quality)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<StageQuality>, D::Error> {
+            Ok(Option::<String>::deserialize(deserializer)?.map(|name| super::from_name(&name)))
+        }
+    }
+}
+
+/// The directory that all persisted state is stored in.
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_local_dir().map(|dir| dir.join("rattles"))
+}
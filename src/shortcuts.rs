@@ -0,0 +1,288 @@
+//! Keyboard shortcuts for menu actions, which the user can rebind
+
+use egui::{Key, KeyboardShortcut, Modifiers};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// Actions whose keyboard shortcut can be rebound.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ShortcutAction {
+    OpenFile,
+    Reload,
+    TakeScreenshot,
+    CopyFrame,
+    Quit,
+    PlayPause,
+    Fullscreen,
+    ZoomIn,
+    ZoomOut,
+    ZoomReset,
+    IncreaseUiScale,
+    DecreaseUiScale,
+    Kiosk,
+    HideMenuBar,
+}
+
+impl ShortcutAction {
+    const ALL: [Self; 14] = [
+        Self::OpenFile,
+        Self::Reload,
+        Self::TakeScreenshot,
+        Self::CopyFrame,
+        Self::Quit,
+        Self::PlayPause,
+        Self::Fullscreen,
+        Self::ZoomIn,
+        Self::ZoomOut,
+        Self::ZoomReset,
+        Self::IncreaseUiScale,
+        Self::DecreaseUiScale,
+        Self::Kiosk,
+        Self::HideMenuBar,
+    ];
+
+    /// The name that the action is saved under.
+    fn name(self) -> &'static str {
+        match self {
+            Self::OpenFile => "open-file",
+            Self::Reload => "reload",
+            Self::TakeScreenshot => "take-screenshot",
+            Self::CopyFrame => "copy-frame",
+            Self::Quit => "quit",
+            Self::PlayPause => "play-pause",
+            Self::Fullscreen => "fullscreen",
+            Self::ZoomIn => "zoom-in",
+            Self::ZoomOut => "zoom-out",
+            Self::ZoomReset => "zoom-reset",
+            Self::IncreaseUiScale => "increase-ui-scale",
+            Self::DecreaseUiScale => "decrease-ui-scale",
+            Self::Kiosk => "kiosk",
+            Self::HideMenuBar => "hide-menu-bar",
+        }
+    }
+
+    fn default_shortcut(self) -> KeyboardShortcut {
+        match self {
+            Self::OpenFile => KeyboardShortcut::new(Modifiers::COMMAND, Key::O),
+            Self::Reload => KeyboardShortcut::new(Modifiers::COMMAND, Key::R),
+            Self::TakeScreenshot => {
+                KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::S)
+            }
+            Self::CopyFrame => {
+                KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::C)
+            }
+            Self::Quit => KeyboardShortcut::new(Modifiers::COMMAND, Key::Q),
+            Self::PlayPause => KeyboardShortcut::new(Modifiers::NONE, Key::Space),
+            Self::Fullscreen => KeyboardShortcut::new(Modifiers::NONE, Key::F11),
+            Self::ZoomIn => KeyboardShortcut::new(Modifiers::COMMAND, Key::PlusEquals),
+            Self::ZoomOut => KeyboardShortcut::new(Modifiers::COMMAND, Key::Minus),
+            Self::ZoomReset => KeyboardShortcut::new(Modifiers::COMMAND, Key::Num0),
+            Self::IncreaseUiScale => {
+                KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::PlusEquals)
+            }
+            Self::DecreaseUiScale => {
+                KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Minus)
+            }
+            Self::Kiosk => KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::K),
+            // Cmd+M minimizes the window on macOS.
+            Self::HideMenuBar => {
+                KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::M)
+            }
+        }
+    }
+}
+
+/// Keys that shortcuts and gamepad buttons can be bound to. Escape is left out, as it cancels rebinding.
+pub const BINDABLE_KEYS: [Key; 72] = [
+    Key::ArrowDown,
+    Key::ArrowLeft,
+    Key::ArrowRight,
+    Key::ArrowUp,
+    Key::Tab,
+    Key::Backspace,
+    Key::Enter,
+    Key::Space,
+    Key::Insert,
+    Key::Delete,
+    Key::Home,
+    Key::End,
+    Key::PageUp,
+    Key::PageDown,
+    Key::Minus,
+    Key::PlusEquals,
+    Key::Num0,
+    Key::Num1,
+    Key::Num2,
+    Key::Num3,
+    Key::Num4,
+    Key::Num5,
+    Key::Num6,
+    Key::Num7,
+    Key::Num8,
+    Key::Num9,
+    Key::A,
+    Key::B,
+    Key::C,
+    Key::D,
+    Key::E,
+    Key::F,
+    Key::G,
+    Key::H,
+    Key::I,
+    Key::J,
+    Key::K,
+    Key::L,
+    Key::M,
+    Key::N,
+    Key::O,
+    Key::P,
+    Key::Q,
+    Key::R,
+    Key::S,
+    Key::T,
+    Key::U,
+    Key::V,
+    Key::W,
+    Key::X,
+    Key::Y,
+    Key::Z,
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+    Key::F13,
+    Key::F14,
+    Key::F15,
+    Key::F16,
+    Key::F17,
+    Key::F18,
+    Key::F19,
+    Key::F20,
+];
+
+/// The keyboard shortcuts for every rebindable action.
+/// Only the ones the user has changed are saved, so that the rest follow any change to the defaults.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Shortcuts {
+    overrides: BTreeMap<ShortcutAction, KeyboardShortcut>,
+}
+
+impl Shortcuts {
+    pub fn get(&self, action: ShortcutAction) -> KeyboardShortcut {
+        self.overrides
+            .get(&action)
+            .copied()
+            .unwrap_or_else(|| action.default_shortcut())
+    }
+
+    pub fn set(&mut self, action: ShortcutAction, shortcut: KeyboardShortcut) {
+        if shortcut == action.default_shortcut() {
+            self.overrides.remove(&action);
+        } else {
+            self.overrides.insert(action, shortcut);
+        }
+    }
+
+    /// The other action that the given shortcut is already bound to, if any.
+    pub fn conflict(
+        &self,
+        action: ShortcutAction,
+        shortcut: KeyboardShortcut,
+    ) -> Option<ShortcutAction> {
+        ShortcutAction::ALL
+            .into_iter()
+            .find(|&other| other != action && self.get(other) == shortcut)
+    }
+
+    /// Turns a key press into a shortcut that can be bound, if the key can be.
+    /// Ctrl elsewhere and ⌘ on macOS are both stored as the platform's command key, so that shortcuts carry over
+    /// between platforms. Ctrl on macOS is a key of its own, and is stored as such.
+    pub fn captured(modifiers: Modifiers, key: Key) -> Option<KeyboardShortcut> {
+        if !BINDABLE_KEYS.contains(&key) {
+            return None;
+        }
+        // Outside of macOS, Ctrl is the command key, and is reported as both.
+        let is_ctrl_distinct = modifiers.ctrl && (modifiers.mac_cmd || !modifiers.command);
+        let modifiers = Modifiers {
+            alt: modifiers.alt,
+            ctrl: is_ctrl_distinct,
+            shift: modifiers.shift,
+            command: modifiers.command,
+            ..Modifiers::NONE
+        };
+        Some(KeyboardShortcut::new(modifiers, key))
+    }
+}
+
+/// Shortcuts are saved as text such as `Command+Shift+K`, so that they can be edited by hand.
+fn format_shortcut(shortcut: &KeyboardShortcut) -> String {
+    let mut parts = vec![];
+    if shortcut.modifiers.ctrl {
+        parts.push("Ctrl");
+    }
+    if shortcut.modifiers.command {
+        parts.push("Command");
+    }
+    if shortcut.modifiers.shift {
+        parts.push("Shift");
+    }
+    if shortcut.modifiers.alt {
+        parts.push("Alt");
+    }
+    parts.push(shortcut.key.name());
+    parts.join("+")
+}
+
+fn parse_shortcut(text: &str) -> Option<KeyboardShortcut> {
+    let (modifier_names, key_name) = text.rsplit_once('+').unwrap_or(("", text));
+    let mut modifiers = Modifiers::NONE;
+    for name in modifier_names.split('+').filter(|name| !name.is_empty()) {
+        match name {
+            "Ctrl" => modifiers.ctrl = true,
+            "Command" => modifiers.command = true,
+            "Shift" => modifiers.shift = true,
+            "Alt" => modifiers.alt = true,
+            _ => return None,
+        }
+    }
+    let key = BINDABLE_KEYS
+        .into_iter()
+        .find(|key| key.name() == key_name)?;
+    Some(KeyboardShortcut::new(modifiers, key))
+}
+
+impl Serialize for Shortcuts {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.overrides
+                .iter()
+                .map(|(action, shortcut)| (action.name(), format_shortcut(shortcut))),
+        )
+    }
+}
+
+/// Unknown actions and shortcuts that can't be read are skipped, rather than losing every other preference.
+impl<'de> Deserialize<'de> for Shortcuts {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let saved = BTreeMap::<String, String>::deserialize(deserializer)?;
+        let mut shortcuts = Self::default();
+        for (name, text) in saved {
+            let action = ShortcutAction::ALL
+                .into_iter()
+                .find(|action| action.name() == name);
+            match (action, parse_shortcut(&text)) {
+                (Some(action), Some(shortcut)) => shortcuts.set(action, shortcut),
+                _ => tracing::warn!("Ignoring unknown shortcut {name} = {text}"),
+            }
+        }
+        Ok(shortcuts)
+    }
+}
@@ -0,0 +1,77 @@
+//! Checking whether a newer build of Ruffle has been released
+
+use crate::custom_event::RuffleEvent;
+use anyhow::anyhow;
+use isahc::ReadResponseExt;
+use serde::Deserialize;
+
+/// The most recent releases, newest first. Nightly builds are published as prereleases.
+const RELEASES_URL: &str = "https://api.github.com/repos/ruffle-rs/ruffle/releases?per_page=30";
+
+/// A release of Ruffle that's newer than this build.
+#[derive(Clone, Debug)]
+pub struct Release {
+    /// The name of the release, such as `nightly-2023-06-30` or `v0.1.0`.
+    pub name: String,
+    /// The page that the release can be downloaded from.
+    pub url: String,
+}
+
+#[derive(Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    html_url: String,
+    prerelease: bool,
+}
+
+/// Looks for a newer release on a background thread, and hands the outcome to `send` as
+/// `RuffleEvent::UpdateCheckResult`. A result of `None` means this build is up to date.
+pub fn check_for_updates(send: impl FnOnce(RuffleEvent) + Send + 'static) {
+    std::thread::spawn(move || {
+        let result = newer_release();
+        if let Err(e) = &result {
+            tracing::warn!("Couldn't check for updates: {e:#}");
+        }
+        send(RuffleEvent::UpdateCheckResult(result));
+    });
+}
+
+fn newer_release() -> anyhow::Result<Option<Release>> {
+    let mut response = isahc::get(RELEASES_URL)?;
+    if !response.status().is_success() {
+        return Err(anyhow!(
+            "{RELEASES_URL} responded with {}",
+            response.status()
+        ));
+    }
+    let releases: Vec<GithubRelease> = serde_json::from_str(&response.text()?)?;
+    let channel = env!("CFG_RELEASE_CHANNEL");
+    let newer = if channel == "nightly" || channel == "dev" {
+        // Nightlies are named after the day they were built, which compares in order as text.
+        releases.into_iter().find(|release| {
+            release
+                .tag_name
+                .strip_prefix("nightly-")
+                .map_or(false, |date| date > env!("VERGEN_GIT_COMMIT_DATE"))
+        })
+    } else {
+        let current = parse_version(env!("CARGO_PKG_VERSION"));
+        releases.into_iter().find(|release| {
+            !release.prerelease
+                && release
+                    .tag_name
+                    .strip_prefix('v')
+                    .and_then(parse_version)
+                    .map_or(false, |version| Some(version) > current)
+        })
+    };
+    Ok(newer.map(|release| Release {
+        name: release.tag_name,
+        url: release.html_url,
+    }))
+}
+
+/// Reads a version such as `0.1.0` into numbers that compare in release order.
+fn parse_version(version: &str) -> Option<Vec<u32>> {
+    version.split('.').map(|part| part.parse().ok()).collect()
+}
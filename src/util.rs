@@ -1,7 +1,9 @@
 use crate::custom_event::RuffleEvent;
+use crate::preferences::config_dir;
 use anyhow::{anyhow, Error};
 use rfd::FileDialog;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use url::Url;
 use winit::dpi::PhysicalSize;
 use winit::event::{ModifiersState, VirtualKeyCode};
@@ -296,6 +298,15 @@ pub fn get_screen_size(event_loop: &EventLoop<RuffleEvent>) -> PhysicalSize<u32>
     (width, height).into()
 }
 
+/// Returns `true` if the platform's usual shortcut modifier is held: Cmd on macOS, Ctrl elsewhere.
+pub fn is_command_down(modifiers: ModifiersState) -> bool {
+    if cfg!(target_os = "macos") {
+        modifiers.logo()
+    } else {
+        modifiers.ctrl()
+    }
+}
+
 pub fn parse_url(path: &Path) -> Result<Url, Error> {
     if path.exists() {
         let absolute_path = path.canonicalize().unwrap_or_else(|_| path.to_owned());
@@ -309,10 +320,254 @@ pub fn parse_url(path: &Path) -> Result<Url, Error> {
     }
 }
 
-pub fn pick_file() -> Option<PathBuf> {
-    FileDialog::new()
-        .add_filter("Project Files", &["json"])
-        .add_filter("All Files", &["*"])
+/// URL schemes that movies can be loaded over.
+const SUPPORTED_SCHEMES: &[&str] = &["http", "https", "file"];
+
+/// What the input to the Open URL prompt looks like, so that common mistakes can be pointed out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UrlInputKind {
+    /// A URL that can be opened as it is.
+    Url(Url),
+    /// A path to a local file, such as `C:\game.swf`, which may not exist.
+    LocalPath(PathBuf),
+    /// A web address without its scheme, such as `example.com/game.swf`, along with the URL it most likely means.
+    MissingScheme(Url),
+    /// A URL with a scheme that movies can't be loaded over, such as `ftp`.
+    UnsupportedScheme(String),
+    /// Anything else, along with why it can't be parsed as a URL.
+    Invalid(String),
+}
+
+/// Works out what the given input to the Open URL prompt is.
+pub fn classify_url_input(input: &str) -> UrlInputKind {
+    let input = input.trim();
+    // Windows paths would otherwise be parsed as URLs with the drive letter as their scheme.
+    let is_windows_path = matches!(
+        input.as_bytes(),
+        [drive, b':', b'\\' | b'/', ..] if drive.is_ascii_alphabetic()
+    );
+    let is_path = is_windows_path
+        || input.starts_with(['/', '\\'])
+        || input.starts_with("./")
+        || input.starts_with("../")
+        || Path::new(input).exists();
+    if is_path {
+        return UrlInputKind::LocalPath(PathBuf::from(input));
+    }
+    match Url::parse(input) {
+        Ok(url) if SUPPORTED_SCHEMES.contains(&url.scheme()) => UrlInputKind::Url(url),
+        Ok(url) => UrlInputKind::UnsupportedScheme(url.scheme().to_string()),
+        Err(url::ParseError::RelativeUrlWithoutBase) => {
+            // Only suggest a scheme if the start of the input looks like a domain.
+            match Url::parse(&format!("https://{input}")) {
+                Ok(url)
+                    if url
+                        .host_str()
+                        .map_or(false, |host| host.contains('.') || host == "localhost") =>
+                {
+                    UrlInputKind::MissingScheme(url)
+                }
+                _ => UrlInputKind::Invalid(url::ParseError::RelativeUrlWithoutBase.to_string()),
+            }
+        }
+        Err(e) => UrlInputKind::Invalid(e.to_string()),
+    }
+}
+
+/// File extensions of projects that can be opened.
+pub const PROJECT_EXTENSIONS: &[&str] = &["json"];
+
+/// Returns `true` if `path` looks like a project that can be opened, judging by its extension.
+pub fn is_supported_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .map_or(false, |extension| {
+            PROJECT_EXTENSIONS
+                .iter()
+                .any(|supported| supported.eq_ignore_ascii_case(extension))
+        })
+}
+
+/// Returns `true` if images can be put on the system clipboard.
+/// arboard can do that wherever it can open the clipboard at all, which needs a display server on Linux.
+pub fn clipboard_supports_images() -> bool {
+    arboard::Clipboard::new().is_ok()
+}
+
+/// The file that log output of the current session is written to, next to the preferences.
+pub fn log_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("rattles.log"))
+}
+
+pub fn pick_file(directory: Option<&Path>) -> Option<PathBuf> {
+    project_file_dialog(directory)
         .set_title("Load a project")
         .pick_file()
 }
+
+pub fn pick_files(directory: Option<&Path>) -> Option<Vec<PathBuf>> {
+    project_file_dialog(directory)
+        .set_title("Add projects to the playlist")
+        .pick_files()
+}
+
+fn project_file_dialog(directory: Option<&Path>) -> FileDialog {
+    let dialog = FileDialog::new()
+        .add_filter("Project Files", PROJECT_EXTENSIONS)
+        .add_filter("All Files", &["*"]);
+    match directory {
+        Some(directory) => dialog.set_directory(directory),
+        None => dialog,
+    }
+}
+
+pub fn pick_recording_dir() -> Option<PathBuf> {
+    FileDialog::new()
+        .set_title("Choose a folder for the recorded frames")
+        .pick_folder()
+}
+
+/// Asks where to save a screenshot, starting out in `directory` if given.
+/// The image format is told by the extension, so `.png` is added if the user left it out.
+pub fn pick_screenshot_path(file_name: &str, directory: Option<&Path>) -> Option<PathBuf> {
+    let mut dialog = FileDialog::new()
+        .add_filter("PNG Images", &["png"])
+        .set_file_name(file_name)
+        .set_title("Save screenshot");
+    if let Some(directory) = directory {
+        dialog = dialog.set_directory(directory);
+    }
+    let mut path = dialog.save_file()?;
+    if path.extension().is_none() {
+        path.set_extension("png");
+    }
+    Some(path)
+}
+
+pub fn pick_console_log_path() -> Option<PathBuf> {
+    FileDialog::new()
+        .add_filter("Text Files", &["txt", "log"])
+        .set_file_name("ruffle.log")
+        .set_title("Save console output")
+        .save_file()
+}
+
+/// Whether the system asks for animations to be reduced, or `None` if that can't be told.
+/// This is the "Animation effects" setting, which Windows keeps for every application to follow.
+#[cfg(windows)]
+pub fn system_prefers_reduced_motion() -> Option<bool> {
+    use windows_sys::Win32::Foundation::BOOL;
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        SystemParametersInfoW, SPI_GETCLIENTAREAANIMATION,
+    };
+    let mut animate: BOOL = 0;
+    // SAFETY: This setting is read into a BOOL, which `animate` is.
+    let result = unsafe {
+        SystemParametersInfoW(
+            SPI_GETCLIENTAREAANIMATION,
+            0,
+            &mut animate as *mut BOOL as *mut std::ffi::c_void,
+            0,
+        )
+    };
+    (result != 0).then_some(animate == 0)
+}
+
+/// Whether the system asks for animations to be reduced, or `None` if that can't be told.
+/// The setting is read through the platform's own command-line tools, so this can take a while and is best called
+/// on a background thread. Elsewhere than GNOME on Linux it usually can't be told.
+#[cfg(not(windows))]
+pub fn system_prefers_reduced_motion() -> Option<bool> {
+    let output = if cfg!(target_os = "macos") {
+        Command::new("defaults")
+            .args(["read", "com.apple.universalaccess", "reduceMotion"])
+            .output()
+    } else {
+        Command::new("gsettings")
+            .args(["get", "org.gnome.desktop.interface", "enable-animations"])
+            .output()
+    }
+    .ok()
+    .filter(|output| output.status.success())?;
+    let output = String::from_utf8_lossy(&output.stdout);
+    let value = output.split_whitespace().last()?;
+    Some(if cfg!(target_os = "macos") {
+        value == "1"
+    } else {
+        value == "false"
+    })
+}
+
+/// The system's light or dark preference, for platforms where winit can't tell it.
+/// This is only read on Linux, from GNOME's setting, which other desktops often follow too.
+/// That means running gsettings, so this is best called on a background thread.
+pub fn system_theme() -> Option<winit::window::Theme> {
+    if cfg!(any(target_os = "macos", windows)) {
+        return None;
+    }
+    let output = Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "color-scheme"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "'prefer-dark'" => Some(winit::window::Theme::Dark),
+        "'prefer-light'" | "'default'" => Some(winit::window::Theme::Light),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_url_input_accepts_supported_urls() {
+        assert_eq!(
+            classify_url_input("https://example.com/game.swf"),
+            UrlInputKind::Url(Url::parse("https://example.com/game.swf").unwrap())
+        );
+    }
+
+    #[test]
+    fn classify_url_input_adds_missing_scheme() {
+        assert_eq!(
+            classify_url_input("example.com/foo.swf"),
+            UrlInputKind::MissingScheme(Url::parse("https://example.com/foo.swf").unwrap())
+        );
+    }
+
+    #[test]
+    fn classify_url_input_recognizes_windows_paths() {
+        assert_eq!(
+            classify_url_input(r"C:\game.swf"),
+            UrlInputKind::LocalPath(PathBuf::from(r"C:\game.swf"))
+        );
+        assert_eq!(
+            classify_url_input("c:/game.swf"),
+            UrlInputKind::LocalPath(PathBuf::from("c:/game.swf"))
+        );
+    }
+
+    #[test]
+    fn classify_url_input_rejects_unsupported_schemes() {
+        assert_eq!(
+            classify_url_input("ftp://host/x.swf"),
+            UrlInputKind::UnsupportedScheme("ftp".to_string())
+        );
+    }
+
+    #[test]
+    fn classify_url_input_rejects_empty_input() {
+        assert!(matches!(classify_url_input(""), UrlInputKind::Invalid(_)));
+        assert!(matches!(classify_url_input("  \t "), UrlInputKind::Invalid(_)));
+    }
+
+    #[test]
+    fn classify_url_input_ignores_surrounding_whitespace() {
+        assert_eq!(
+            classify_url_input("  https://example.com/game.swf\n"),
+            UrlInputKind::Url(Url::parse("https://example.com/game.swf").unwrap())
+        );
+    }
+}